@@ -1,29 +1,655 @@
-/// Lightweight in-app audio processing pipeline.
-///
-/// This is intentionally minimal to keep latency low. We'll expand it with
-/// open-source components like RNNoise and WebRTC APM in later iterations.
+use std::collections::VecDeque;
+use std::f32::consts::PI;
+
+use rustfft::num_complex::Complex32;
+use rustfft::{Fft, FftPlanner};
+
+use crate::obs::{AudioFilterPreset, FilterConfig};
+
+/// Peak envelope decay, expressed as a time constant: how long the noise gate's envelope
+/// follower takes to forget a transient once the signal drops, independent of the gate's own
+/// attack/release ramps. Matches a typical fast peak detector.
+const GATE_ENVELOPE_DECAY_MS: f32 = 5.0;
+
+/// Hop size: 10ms at 48kHz. The recorder does not resample, so at other capture rates a hop
+/// spans a different duration.
+const FRAME_SIZE: usize = 480;
+/// Analysis window, 50% overlap with the previous frame so the RNNoise stage can apply
+/// per-band gains without introducing block artifacts at the hop boundaries.
+const FFT_SIZE: usize = FRAME_SIZE * 2;
+/// Bark-scale bands the spectrum is pooled into before gain prediction, mirroring RNNoise's
+/// band layout rather than working bin-by-bin.
+const NUM_BANDS: usize = 22;
+const SAMPLE_RATE: f32 = 48_000.0;
+
+fn hz_to_bark(hz: f32) -> f32 {
+    13.0 * (0.00076 * hz).atan() + 3.5 * ((hz / 7500.0).powi(2)).atan()
+}
+
+/// Bin index boundaries of `NUM_BANDS` Bark-spaced bands over an `FFT_SIZE`-point real FFT.
+fn bark_band_edges() -> [usize; NUM_BANDS + 1] {
+    let nyquist_bark = hz_to_bark(SAMPLE_RATE / 2.0);
+    let bin_hz = SAMPLE_RATE / FFT_SIZE as f32;
+    let mut edges = [0usize; NUM_BANDS + 1];
+
+    for (i, edge) in edges.iter_mut().enumerate() {
+        let bark = nyquist_bark * i as f32 / NUM_BANDS as f32;
+        // Invert hz_to_bark numerically: it's monotonic, so binary search over the bin range.
+        let mut lo = 0u32;
+        let mut hi = (FFT_SIZE / 2) as u32;
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if hz_to_bark(mid as f32 * bin_hz) < bark {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        *edge = lo as usize;
+    }
+    edges[NUM_BANDS] = FFT_SIZE / 2;
+    edges
+}
+
+fn hann_window(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|n| 0.5 - 0.5 * (2.0 * PI * n as f32 / (len - 1) as f32).cos())
+        .collect()
+}
+
+/// Frame-based noise suppressor: per-band spectral gain driven by a running noise-floor
+/// estimate, standing in for RNNoise's trained GRU with a lightweight recursive smoother.
+struct RnnoiseStage {
+    fft: std::sync::Arc<dyn Fft<f32>>,
+    ifft: std::sync::Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    band_edges: [usize; NUM_BANDS + 1],
+    analysis_buffer: Vec<f32>,
+    synthesis_overlap: Vec<f32>,
+    noise_floor: [f32; NUM_BANDS],
+    band_gain: [f32; NUM_BANDS],
+    vad_probability: f32,
+    warmed_up: bool,
+}
+
+impl RnnoiseStage {
+    fn new() -> Self {
+        let mut planner = FftPlanner::new();
+        Self {
+            fft: planner.plan_fft_forward(FFT_SIZE),
+            ifft: planner.plan_fft_inverse(FFT_SIZE),
+            window: hann_window(FFT_SIZE),
+            band_edges: bark_band_edges(),
+            analysis_buffer: vec![0.0; FFT_SIZE],
+            synthesis_overlap: vec![0.0; FRAME_SIZE],
+            noise_floor: [1e-4; NUM_BANDS],
+            band_gain: [1.0; NUM_BANDS],
+            vad_probability: 0.0,
+            warmed_up: false,
+        }
+    }
+
+    /// Denoise one `FRAME_SIZE` hop in place, using the previous hop as overlap context.
+    fn process(&mut self, frame: &mut [f32]) {
+        debug_assert_eq!(frame.len(), FRAME_SIZE);
+
+        // Slide the new hop into the back half of the analysis window.
+        self.analysis_buffer.copy_within(FRAME_SIZE.., 0);
+        self.analysis_buffer[FRAME_SIZE..].copy_from_slice(frame);
+
+        let mut spectrum: Vec<Complex32> = self
+            .analysis_buffer
+            .iter()
+            .zip(&self.window)
+            .map(|(sample, w)| Complex32::new(sample * w, 0.0))
+            .collect();
+        self.fft.process(&mut spectrum);
+
+        self.update_band_gains(&spectrum);
+        self.apply_band_gains(&mut spectrum);
+
+        self.ifft.process(&mut spectrum);
+        let scale = 1.0 / FFT_SIZE as f32;
+
+        // Overlap-add: the first half of the inverse transform completes the previous hop,
+        // the second half becomes the overlap context carried into the next call.
+        for i in 0..FRAME_SIZE {
+            let synthesized = spectrum[i].re * scale * self.window[i];
+            frame[i] = self.synthesis_overlap[i] + synthesized;
+        }
+        for i in 0..FRAME_SIZE {
+            self.synthesis_overlap[i] = spectrum[FRAME_SIZE + i].re * scale * self.window[FRAME_SIZE + i];
+        }
+
+        self.warmed_up = true;
+    }
+
+    fn update_band_gains(&mut self, spectrum: &[Complex32]) {
+        let mut total_snr = 0.0;
+
+        for band in 0..NUM_BANDS {
+            let lo = self.band_edges[band];
+            let hi = self.band_edges[band + 1].max(lo + 1);
+
+            let energy: f32 = spectrum[lo..hi].iter().map(|c| c.norm_sqr()).sum::<f32>()
+                / (hi - lo) as f32;
+
+            // Minimum-statistics noise tracking: snap down immediately to quieter periods, rise
+            // only slowly towards louder ones so transients aren't mistaken for a rising noise floor.
+            if energy < self.noise_floor[band] || !self.warmed_up {
+                self.noise_floor[band] = energy;
+            } else {
+                self.noise_floor[band] += (energy - self.noise_floor[band]) * 0.01;
+            }
+
+            let snr = energy / (self.noise_floor[band] + 1e-8);
+            total_snr += snr;
+
+            // Wiener-style gain from the instantaneous SNR, with a floor so speech onsets
+            // aren't chopped while the noise estimate catches up.
+            let raw_gain = (snr / (1.0 + snr)).max(0.1);
+            // Single-pole smoothing across frames plays the role RNNoise's GRU would: it
+            // prevents the gain from chattering frame to frame.
+            self.band_gain[band] = self.band_gain[band] * 0.6 + raw_gain * 0.4;
+        }
+
+        let vad_raw = (total_snr / NUM_BANDS as f32 / (1.0 + total_snr / NUM_BANDS as f32)).clamp(0.0, 1.0);
+        self.vad_probability = self.vad_probability * 0.8 + vad_raw * 0.2;
+    }
+
+    fn apply_band_gains(&self, spectrum: &mut [Complex32]) {
+        for band in 0..NUM_BANDS {
+            let lo = self.band_edges[band];
+            let hi = self.band_edges[band + 1].max(lo + 1);
+            let gain = self.band_gain[band];
+
+            for bin in lo..hi {
+                spectrum[bin] *= gain;
+                // Mirror bin for the real-valued signal's conjugate-symmetric upper half.
+                let mirror = FFT_SIZE - bin;
+                if mirror < FFT_SIZE && mirror != bin {
+                    spectrum[mirror] *= gain;
+                }
+            }
+        }
+    }
+}
+
+/// Limiter with a soft knee: samples inside the knee are compressed smoothly towards the
+/// threshold instead of being clamped outright, which avoids the harsh clipping artifacts a
+/// brick-wall limiter produces on percussive speech (plosives, desk bumps).
+struct SoftKneeLimiter {
+    threshold: f32,
+    knee_width: f32,
+}
+
+impl SoftKneeLimiter {
+    fn new(threshold: f32, knee_width: f32) -> Self {
+        Self { threshold, knee_width }
+    }
+
+    fn apply(&self, sample: f32) -> f32 {
+        let sign = sample.signum();
+        let mag = sample.abs();
+        let knee_start = self.threshold - self.knee_width / 2.0;
+        let knee_end = self.threshold + self.knee_width / 2.0;
+
+        if mag <= knee_start {
+            sample
+        } else if mag >= knee_end {
+            sign * self.threshold
+        } else {
+            let t = (mag - knee_start) / self.knee_width;
+            let eased = t * t * (3.0 - 2.0 * t); // smoothstep
+            sign * (knee_start + eased * (self.threshold - knee_start))
+        }
+    }
+}
+
+/// Envelope-follower noise gate, built from an `AudioFilterPreset`'s `"noise_gate"` filter
+/// settings. Tracks a peak envelope, opens once it rises above `open_threshold_db`, stays open
+/// until it falls below `close_threshold_db` for `hold_time`, then ramps the gain closed.
+struct NoiseGate {
+    open_threshold_db: f32,
+    close_threshold_db: f32,
+    attack_per_sample: f32,
+    release_per_sample: f32,
+    hold_samples: u32,
+    envelope_decay: f32,
+    env: f32,
+    gain: f32,
+    open: bool,
+    hold_remaining: u32,
+}
+
+impl NoiseGate {
+    fn new(
+        open_threshold_db: f32,
+        close_threshold_db: f32,
+        attack_time_ms: f32,
+        hold_time_ms: f32,
+        release_time_ms: f32,
+        sample_rate: f32,
+    ) -> Self {
+        let attack_samples = (attack_time_ms / 1000.0 * sample_rate).max(1.0);
+        let release_samples = (release_time_ms / 1000.0 * sample_rate).max(1.0);
+        let envelope_decay = (-1.0 / (GATE_ENVELOPE_DECAY_MS / 1000.0 * sample_rate)).exp();
+
+        Self {
+            open_threshold_db,
+            close_threshold_db,
+            attack_per_sample: 1.0 / attack_samples,
+            release_per_sample: 1.0 / release_samples,
+            hold_samples: (hold_time_ms / 1000.0 * sample_rate) as u32,
+            envelope_decay,
+            env: 0.0,
+            gain: 0.0,
+            open: false,
+            hold_remaining: 0,
+        }
+    }
+
+    fn process(&mut self, sample: f32) -> f32 {
+        self.env = sample.abs().max(self.env * self.envelope_decay);
+        let env_db = 20.0 * self.env.max(1e-8).log10();
+
+        if env_db > self.open_threshold_db {
+            self.open = true;
+            self.hold_remaining = self.hold_samples;
+        } else if self.open && env_db <= self.close_threshold_db {
+            if self.hold_remaining > 0 {
+                self.hold_remaining -= 1;
+            } else {
+                self.open = false;
+            }
+        }
+
+        if self.open {
+            self.gain = (self.gain + self.attack_per_sample).min(1.0);
+        } else {
+            self.gain = (self.gain - self.release_per_sample).max(0.0);
+        }
+
+        sample * self.gain
+    }
+}
+
+/// Feed-forward compressor, built from an `AudioFilterPreset`'s `"compressor"` filter settings.
+/// Gain reduction is computed per-sample from the instantaneous level, then smoothed with
+/// separate attack/release time constants before being applied alongside the makeup gain.
+struct Compressor {
+    threshold_db: f32,
+    ratio: f32,
+    attack_coef: f32,
+    release_coef: f32,
+    output_gain_db: f32,
+    gain_reduction_db: f32,
+}
+
+impl Compressor {
+    fn new(
+        threshold_db: f32,
+        ratio: f32,
+        attack_time_ms: f32,
+        release_time_ms: f32,
+        output_gain_db: f32,
+        sample_rate: f32,
+    ) -> Self {
+        Self {
+            threshold_db,
+            ratio: ratio.max(1.0),
+            attack_coef: (-1.0 / (attack_time_ms * sample_rate / 1000.0)).exp(),
+            release_coef: (-1.0 / (release_time_ms * sample_rate / 1000.0)).exp(),
+            output_gain_db,
+            gain_reduction_db: 0.0,
+        }
+    }
+
+    fn process(&mut self, sample: f32) -> f32 {
+        let level_db = 20.0 * sample.abs().max(1e-8).log10();
+        let target_gr_db = if level_db > self.threshold_db {
+            (level_db - self.threshold_db) * (1.0 - 1.0 / self.ratio)
+        } else {
+            0.0
+        };
+
+        let coef = if target_gr_db > self.gain_reduction_db {
+            self.attack_coef
+        } else {
+            self.release_coef
+        };
+        self.gain_reduction_db = coef * self.gain_reduction_db + (1.0 - coef) * target_gr_db;
+
+        let makeup_linear = 10f32.powf((self.output_gain_db - self.gain_reduction_db) / 20.0);
+        sample * makeup_linear
+    }
+}
+
+fn filter_setting(config: &FilterConfig, key: &str, default: f32) -> f32 {
+    config
+        .settings
+        .get(key)
+        .and_then(|v| v.as_f64())
+        .map(|v| v as f32)
+        .unwrap_or(default)
+}
+
+/// Fixed frame size `nnnoiseless` (an RNNoise port) operates on, at its fixed 48kHz rate.
+#[cfg(feature = "rnnoise_suppression")]
+const RNNOISE_FRAME_SIZE: usize = 480;
+#[cfg(feature = "rnnoise_suppression")]
+const RNNOISE_SAMPLE_RATE: f32 = 48_000.0;
+
+/// Background noise suppression for a preset's `"noise_suppression"` filter, backed by the
+/// pure-Rust RNNoise port `nnnoiseless`. Buffers incoming samples at the capture stream's native
+/// `sample_rate`, resamples them into the fixed 480-sample/48kHz frames RNNoise requires, and
+/// resamples the denoised result back down before blending it with the dry signal by
+/// `intensity`. Gated behind the `rnnoise_suppression` cargo feature so the dependency (and its
+/// bundled model weights) stay optional for builds that don't need it; `"speex"`-method presets
+/// fall through to `build_dsp_chain` without a stage, since `nnnoiseless` doesn't implement it.
+#[cfg(feature = "rnnoise_suppression")]
+struct NoiseSuppressionStage {
+    denoise: Box<nnnoiseless::DenoiseState<'static>>,
+    intensity: f32,
+    native_samples_per_frame: usize,
+    input_buffer: Vec<f32>,
+    output_queue: VecDeque<f32>,
+}
+
+#[cfg(feature = "rnnoise_suppression")]
+impl NoiseSuppressionStage {
+    fn new(intensity: f32, sample_rate: f32) -> Self {
+        let native_samples_per_frame =
+            ((RNNOISE_FRAME_SIZE as f32 * sample_rate / RNNOISE_SAMPLE_RATE).ceil() as usize).max(1);
+
+        Self {
+            denoise: nnnoiseless::DenoiseState::new(),
+            intensity: intensity.clamp(0.0, 1.0),
+            native_samples_per_frame,
+            input_buffer: Vec::with_capacity(native_samples_per_frame),
+            output_queue: VecDeque::new(),
+        }
+    }
+
+    fn process(&mut self, sample: f32) -> f32 {
+        self.input_buffer.push(sample);
+
+        if self.input_buffer.len() >= self.native_samples_per_frame {
+            let native_frame = std::mem::replace(
+                &mut self.input_buffer,
+                Vec::with_capacity(self.native_samples_per_frame),
+            );
+
+            let dry_48k = resample_linear(&native_frame, RNNOISE_FRAME_SIZE);
+
+            // nnnoiseless expects i16-range amplitudes, not the -1.0..1.0 floats this pipeline
+            // otherwise uses.
+            let scaled: Vec<f32> = dry_48k.iter().map(|s| s * i16::MAX as f32).collect();
+            let mut denoised = vec![0.0f32; RNNOISE_FRAME_SIZE];
+            let _vad_probability = self.denoise.process_frame(&scaled, &mut denoised);
+            let wet_48k: Vec<f32> = denoised.iter().map(|s| s / i16::MAX as f32).collect();
+
+            let blended_48k: Vec<f32> = dry_48k
+                .iter()
+                .zip(&wet_48k)
+                .map(|(dry, wet)| self.intensity * wet + (1.0 - self.intensity) * dry)
+                .collect();
+
+            let blended_native = resample_linear(&blended_48k, native_frame.len());
+            self.output_queue.extend(blended_native);
+        }
+
+        self.output_queue.pop_front().unwrap_or(0.0)
+    }
+}
+
+/// No-op stand-in for `NoiseSuppressionStage` when the `rnnoise_suppression` feature is off, so
+/// `AudioPipeline` doesn't need its own cfg-gating to hold a `noise_suppression` field.
+#[cfg(not(feature = "rnnoise_suppression"))]
+struct NoiseSuppressionStage;
+
+#[cfg(not(feature = "rnnoise_suppression"))]
+impl NoiseSuppressionStage {
+    // `build_dsp_chain` never constructs this without the feature (there's nothing left to
+    // suppress with), so this stays unused on its own; kept for symmetry with the real impl.
+    #[allow(dead_code)]
+    fn new(_intensity: f32, _sample_rate: f32) -> Self {
+        Self
+    }
+
+    fn process(&mut self, sample: f32) -> f32 {
+        sample
+    }
+}
+
+/// Linear-interpolation resampler used to condition samples for `NoiseSuppressionStage`'s fixed
+/// 48kHz frame size. Adequate here since RNNoise's own denoising dominates audible quality, not
+/// the resampler's band-limiting.
+#[cfg(feature = "rnnoise_suppression")]
+fn resample_linear(input: &[f32], output_len: usize) -> Vec<f32> {
+    if input.is_empty() || output_len == 0 {
+        return vec![0.0; output_len];
+    }
+    if input.len() == output_len {
+        return input.to_vec();
+    }
+
+    let ratio = (input.len() - 1) as f32 / (output_len - 1).max(1) as f32;
+    (0..output_len)
+        .map(|i| {
+            let pos = i as f32 * ratio;
+            let lo = pos.floor() as usize;
+            let hi = (lo + 1).min(input.len() - 1);
+            let frac = pos - lo as f32;
+            input[lo] * (1.0 - frac) + input[hi] * frac
+        })
+        .collect()
+}
+
+/// Build the noise gate, noise suppression, and compressor DSP stages described by `preset`'s
+/// `FilterConfig` entries, skipping any that are disabled or missing from the preset entirely.
+fn build_dsp_chain(
+    preset: &AudioFilterPreset,
+    sample_rate: f32,
+) -> (Option<NoiseGate>, Option<NoiseSuppressionStage>, Option<Compressor>) {
+    let mut noise_gate = None;
+    let mut noise_suppression = None;
+    let mut compressor = None;
+
+    for filter in &preset.filters {
+        if !filter.enabled {
+            continue;
+        }
+        match filter.filter_type.as_str() {
+            "noise_gate" => {
+                noise_gate = Some(NoiseGate::new(
+                    filter_setting(filter, "open_threshold", -35.0),
+                    filter_setting(filter, "close_threshold", -45.0),
+                    filter_setting(filter, "attack_time", 25.0),
+                    filter_setting(filter, "hold_time", 200.0),
+                    filter_setting(filter, "release_time", 150.0),
+                    sample_rate,
+                ));
+            }
+            "noise_suppression" => {
+                let method = filter.settings.get("method").and_then(|v| v.as_str());
+                #[cfg(feature = "rnnoise_suppression")]
+                let supported = matches!(method, Some("rnnoise"));
+                #[cfg(not(feature = "rnnoise_suppression"))]
+                let supported = false;
+
+                if supported {
+                    noise_suppression = Some(NoiseSuppressionStage::new(
+                        filter_setting(filter, "intensity", 0.8),
+                        sample_rate,
+                    ));
+                } else {
+                    eprintln!(
+                        "Preset '{}' requests noise_suppression method {:?}, which this build \
+                         doesn't implement; leaving the real-time suppression stage empty for it",
+                        preset.name, method
+                    );
+                }
+            }
+            "compressor" => {
+                compressor = Some(Compressor::new(
+                    filter_setting(filter, "threshold", -18.0),
+                    filter_setting(filter, "ratio", 4.0),
+                    filter_setting(filter, "attack_time", 6.0),
+                    filter_setting(filter, "release_time", 60.0),
+                    filter_setting(filter, "output_gain", 0.0),
+                    sample_rate,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    (noise_gate, noise_suppression, compressor)
+}
+
+/// In-app real-time audio processing pipeline: an RNNoise-style denoiser feeding an optional
+/// noise-gate and compressor (built from a selected `AudioFilterPreset`), then a soft-knee
+/// limiter, run frame-by-frame as capture is recorded. This is the low-latency, always-on
+/// counterpart to DeepFilterNet, which runs offline over the finished file instead.
 pub struct AudioPipeline {
-    limiter_threshold: f32,
+    rnnoise: RnnoiseStage,
+    noise_gate: Option<NoiseGate>,
+    noise_suppression: Option<NoiseSuppressionStage>,
+    compressor: Option<Compressor>,
+    limiter: SoftKneeLimiter,
+    bypass: bool,
+    input_buffer: Vec<f32>,
+    output_queue: VecDeque<f32>,
 }
 
 impl AudioPipeline {
-    pub fn new() -> Self {
+    pub fn new(sample_rate: f32, preset: Option<&AudioFilterPreset>) -> Self {
+        let (noise_gate, noise_suppression, compressor) = preset
+            .map(|preset| build_dsp_chain(preset, sample_rate))
+            .unwrap_or((None, None, None));
+
         Self {
-            limiter_threshold: 0.98,
+            rnnoise: RnnoiseStage::new(),
+            noise_gate,
+            noise_suppression,
+            compressor,
+            limiter: SoftKneeLimiter::new(0.98, 0.1),
+            bypass: false,
+            input_buffer: Vec::with_capacity(FRAME_SIZE),
+            output_queue: VecDeque::with_capacity(FRAME_SIZE),
         }
     }
 
+    /// Disable (or re-enable) the RNNoise stage, e.g. when the user prefers raw capture.
+    /// The limiter still runs either way to protect against clipping.
+    pub fn set_bypass(&mut self, bypass: bool) {
+        self.bypass = bypass;
+    }
+
+    /// Whether real-time denoising is currently engaged, for surfacing alongside
+    /// `deepfilter_available` in `ServiceStatus`.
+    pub fn is_active(&self) -> bool {
+        !self.bypass
+    }
+
+    /// Process one `FRAME_SIZE`-sample (10ms @ 48kHz) frame in place: the built-in RNNoise-style
+    /// denoiser (unless bypassed, or superseded by a preset's own `noise_suppression` filter —
+    /// running both back to back double-suppresses and oversuppresses into an "underwater"
+    /// sound), then the preset's noise gate, noise suppression and compressor (if any), then the
+    /// soft-knee limiter.
+    pub fn process_frame(&mut self, frame: &mut [f32]) {
+        if !self.bypass && self.noise_suppression.is_none() {
+            self.rnnoise.process(frame);
+        }
+        for sample in frame.iter_mut() {
+            let mut processed = *sample;
+            if let Some(gate) = self.noise_gate.as_mut() {
+                processed = gate.process(processed);
+            }
+            if let Some(suppression) = self.noise_suppression.as_mut() {
+                processed = suppression.process(processed);
+            }
+            if let Some(compressor) = self.compressor.as_mut() {
+                processed = compressor.process(processed);
+            }
+            *sample = self.limiter.apply(processed);
+        }
+    }
+
+    /// Buffer samples one at a time and run them through `process_frame` once a full hop has
+    /// accumulated, so callers driven by a per-sample audio callback (like cpal's) don't need
+    /// to manage framing themselves. Introduces up to one frame (10ms) of latency.
     pub fn process_sample(&mut self, sample: f32) -> f32 {
-        self.apply_limiter(sample)
+        self.input_buffer.push(sample);
+
+        if self.input_buffer.len() == FRAME_SIZE {
+            let mut frame = std::mem::replace(&mut self.input_buffer, Vec::with_capacity(FRAME_SIZE));
+            self.process_frame(&mut frame);
+            self.output_queue.extend(frame);
+        }
+
+        self.output_queue.pop_front().unwrap_or(0.0)
     }
+}
 
-    fn apply_limiter(&self, sample: f32) -> f32 {
-        if sample > self.limiter_threshold {
-            self.limiter_threshold
-        } else if sample < -self.limiter_threshold {
-            -self.limiter_threshold
-        } else {
-            sample
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bark_band_edges_cover_full_spectrum() {
+        let edges = bark_band_edges();
+        assert_eq!(edges[0], 0);
+        assert_eq!(edges[NUM_BANDS], FFT_SIZE / 2);
+        for pair in edges.windows(2) {
+            assert!(pair[0] <= pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_soft_knee_limiter_passes_quiet_samples_unchanged() {
+        let limiter = SoftKneeLimiter::new(0.98, 0.1);
+        assert_eq!(limiter.apply(0.5), 0.5);
+        assert_eq!(limiter.apply(-0.5), -0.5);
+    }
+
+    #[test]
+    fn test_soft_knee_limiter_clamps_loud_samples_to_threshold() {
+        let limiter = SoftKneeLimiter::new(0.98, 0.1);
+        assert_eq!(limiter.apply(2.0), 0.98);
+        assert_eq!(limiter.apply(-2.0), -0.98);
+    }
+
+    #[test]
+    fn test_soft_knee_limiter_smooths_the_knee_region() {
+        let limiter = SoftKneeLimiter::new(0.98, 0.1);
+        let knee_sample = limiter.apply(0.98);
+        assert!(knee_sample > 0.93 && knee_sample <= 0.98);
+    }
+
+    #[test]
+    fn test_pipeline_defaults_to_active_suppression() {
+        let pipeline = AudioPipeline::new(SAMPLE_RATE, None);
+        assert!(pipeline.is_active());
+    }
+
+    #[test]
+    fn test_pipeline_bypass_toggle() {
+        let mut pipeline = AudioPipeline::new(SAMPLE_RATE, None);
+        pipeline.set_bypass(true);
+        assert!(!pipeline.is_active());
+    }
+
+    #[test]
+    fn test_process_sample_buffers_until_a_full_frame() {
+        let mut pipeline = AudioPipeline::new(SAMPLE_RATE, None);
+        pipeline.set_bypass(true);
+        for _ in 0..FRAME_SIZE - 1 {
+            assert_eq!(pipeline.process_sample(0.1), 0.0);
         }
+        // The frame-completing sample flushes the whole hop through the output queue.
+        let _ = pipeline.process_sample(0.1);
+        assert_eq!(pipeline.output_queue.len(), FRAME_SIZE - 1);
     }
 }
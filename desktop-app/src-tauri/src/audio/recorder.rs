@@ -1,20 +1,197 @@
-use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
 use std::thread;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Context, Result};
+use chrono::Utc;
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use cpal::SampleFormat;
 use crossbeam_channel::{bounded, Receiver, RecvTimeoutError, Sender};
-use hound::{SampleFormat as WavSampleFormat, WavSpec, WavWriter};
+use hound::{SampleFormat as WavSampleFormat, WavReader, WavSpec, WavWriter};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use uuid::Uuid;
 
 use super::AudioPipeline;
+use crate::obs::AudioFilterPreset;
+
+/// Capacity of the level-meter broadcast channel; deliberately small since only the live meter
+/// UI subscribes and a missed frame at ~30Hz is imperceptible.
+const LEVEL_CHANNEL_CAPACITY: usize = 16;
+/// Target rate for `audio-level` events, matching a typical UI meter's redraw rate.
+const LEVEL_EMIT_HZ: f32 = 30.0;
+/// Capacity of the waveform-meter broadcast channel; same reasoning as `LEVEL_CHANNEL_CAPACITY`.
+const METER_CHANNEL_CAPACITY: usize = 16;
+/// Target cadence for `MeterFrame`s, per the ~50ms VU-meter/waveform refresh a scrolling
+/// waveform UI needs without flooding the frontend.
+const METER_EMIT_HZ: f32 = 20.0;
+/// Number of decimated min/max buckets per `MeterFrame`'s envelope, giving the waveform UI a
+/// handful of points per ~50ms window rather than every raw sample.
+const METER_ENVELOPE_BUCKETS: usize = 8;
+/// How far above `silence_threshold` the level must climb to be considered active again. Without
+/// this margin, a level hovering right at the threshold (e.g. a breath between words) would
+/// flicker in and out of "silent" and chop speech.
+const SILENCE_EXIT_MARGIN: f32 = 1.5;
+/// Minimum consecutive duration below threshold before a region counts as trimmable silence,
+/// so brief pauses between words survive trimming.
+const MIN_SILENCE_DURATION_SECS: f32 = 1.0;
+/// A recording whose peak sample never rises above this, expressed in dBFS, is treated as
+/// silence (dead mic, muted input, started-and-immediately-stopped) and discarded on `stop()`
+/// rather than left behind as a useless WAV file.
+const SILENCE_FLOOR_DBFS: f32 = -60.0;
+
+/// One ~30Hz reading from the live level meter, broadcast to the frontend as an `audio-level`
+/// window event.
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioLevel {
+    pub rms: f32,
+    pub peak: f32,
+    pub is_silent: bool,
+}
+
+/// One ~20Hz (every ~50ms) reading from the live waveform meter, broadcast to the frontend as a
+/// `meter-frame` window event. Distinct from `AudioLevel`: this carries dBFS (rather than linear
+/// amplitude) and a decimated min/max envelope for a scrolling waveform, not just a single VU
+/// needle.
+#[derive(Debug, Clone, Serialize)]
+pub struct MeterFrame {
+    pub rms_dbfs: f32,
+    pub peak_dbfs: f32,
+    /// Decimated `(min, max)` amplitude pairs across the window: `METER_ENVELOPE_BUCKETS` of them
+    /// when the window divides evenly into buckets, plus one for the remainder otherwise.
+    pub envelope: Vec<(f32, f32)>,
+}
+
+fn amplitude_to_dbfs(amplitude: f32) -> f32 {
+    20.0 * amplitude.max(1e-8).log10()
+}
+
+/// Accumulates raw samples in the real-time audio callback and flushes a decimated `MeterFrame`
+/// once a ~50ms window fills, so the callback itself never blocks on anything beyond a `Vec::push`.
+struct MeterAccumulator {
+    window_size: usize,
+    bucket_size: usize,
+    samples: Vec<f32>,
+}
+
+impl MeterAccumulator {
+    fn new(sample_rate: u32) -> Self {
+        let window_size = ((sample_rate as f32 / METER_EMIT_HZ) as usize).max(1);
+        let bucket_size = (window_size / METER_ENVELOPE_BUCKETS).max(1);
+        Self {
+            window_size,
+            bucket_size,
+            samples: Vec::with_capacity(window_size),
+        }
+    }
+
+    /// Feed one raw (ungained) sample; returns a fresh `MeterFrame` once the window has filled.
+    fn push(&mut self, sample: f32) -> Option<MeterFrame> {
+        self.samples.push(sample);
+        if self.samples.len() < self.window_size {
+            return None;
+        }
+
+        let rms = (self.samples.iter().map(|s| s * s).sum::<f32>() / self.samples.len() as f32).sqrt();
+        let peak = self.samples.iter().fold(0.0f32, |acc, s| acc.max(s.abs()));
+
+        let envelope = self
+            .samples
+            .chunks(self.bucket_size)
+            .map(|chunk| {
+                let min = chunk.iter().cloned().fold(f32::INFINITY, f32::min);
+                let max = chunk.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+                (min, max)
+            })
+            .collect();
+
+        self.samples.clear();
+
+        Some(MeterFrame {
+            rms_dbfs: amplitude_to_dbfs(rms),
+            peak_dbfs: amplitude_to_dbfs(peak),
+            envelope,
+        })
+    }
+}
+
+/// An available audio input device, as returned by `NativeRecorderController::list_input_devices`.
+#[derive(Debug, Clone, Serialize)]
+pub struct InputDeviceInfo {
+    pub name: String,
+    pub is_default: bool,
+    pub default_sample_rate: u32,
+    pub default_channels: u16,
+    pub supported_sample_formats: Vec<String>,
+}
+
+/// Caller-requested recording device/format, resolved against what's actually connected when the
+/// recorder thread starts. `None` fields fall back to the device's own default.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingDeviceRequest {
+    pub device_name: Option<String>,
+    pub sample_rate: Option<u32>,
+    pub channels: Option<u16>,
+}
+
+/// Provenance sidecar written next to each recording's WAV as `<name>.json`, so the file is
+/// self-describing for later import instead of being just a bare timestamped WAV. Written with
+/// `end_time`/`duration_seconds`/`sample_count`/`bytes` unset on `start`, then filled in on
+/// `stop`; `duration_seconds`/`bytes` mirror the fields `OBSRecordingStatus` already uses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordingMetadata {
+    recording_id: String,
+    start_time: String,
+    end_time: Option<String>,
+    device_name: String,
+    sample_rate: u32,
+    channels: u16,
+    sample_format: String,
+    preset: Option<AudioFilterPreset>,
+    duration_seconds: u64,
+    sample_count: u64,
+    bytes: u64,
+}
+
+/// `<name>.wav` -> `<name>.json`, alongside the recording.
+fn sidecar_path_for(wav_path: &Path) -> PathBuf {
+    wav_path.with_extension("json")
+}
+
+fn write_sidecar(sidecar_path: &Path, metadata: &RecordingMetadata) -> Result<()> {
+    let json = serde_json::to_string_pretty(metadata)
+        .context("Failed to serialize recording metadata")?;
+    std::fs::write(sidecar_path, json).context("Failed to write recording metadata sidecar")?;
+    Ok(())
+}
+
+/// Fill in `end_time`/`duration_seconds`/`sample_count`/`bytes` on an existing sidecar written by
+/// `write_sidecar` at `start`.
+fn finalize_sidecar(sidecar_path: &Path, output_path: &Path, duration_seconds: u64) -> Result<()> {
+    let contents = std::fs::read_to_string(sidecar_path)
+        .context("Failed to read recording metadata sidecar")?;
+    let mut metadata: RecordingMetadata =
+        serde_json::from_str(&contents).context("Failed to parse recording metadata sidecar")?;
+
+    metadata.end_time = Some(Utc::now().to_rfc3339());
+    metadata.duration_seconds = duration_seconds;
+    metadata.sample_count = WavReader::open(output_path)
+        .map(|reader| reader.duration() as u64)
+        .unwrap_or(0);
+    metadata.bytes = std::fs::metadata(output_path).map(|m| m.len()).unwrap_or(0);
+
+    write_sidecar(sidecar_path, &metadata)
+}
 
 enum RecorderCommand {
-    Start { resp: Sender<Result<PathBuf>> },
-    Stop { resp: Sender<Result<PathBuf>> },
+    Start {
+        request: RecordingDeviceRequest,
+        preset: Option<AudioFilterPreset>,
+        resp: Sender<Result<PathBuf>>,
+    },
+    Stop { trim_silence: bool, resp: Sender<Result<Option<PathBuf>>> },
     Pause { resp: Sender<Result<()>> },
     Resume { resp: Sender<Result<()>> },
     IsRecording { resp: Sender<bool> },
@@ -23,29 +200,106 @@ enum RecorderCommand {
 #[derive(Clone)]
 pub struct NativeRecorderController {
     command_tx: Sender<RecorderCommand>,
+    suppression_active: Arc<AtomicBool>,
+    level_tx: broadcast::Sender<AudioLevel>,
+    meter_tx: broadcast::Sender<MeterFrame>,
+    mic_sensitivity: Arc<AtomicU32>,
+    silence_threshold: Arc<AtomicU32>,
 }
 
 impl NativeRecorderController {
-    pub fn new() -> Self {
+    pub fn new(mic_sensitivity: f32, silence_threshold: f32) -> Self {
         let (command_tx, command_rx) = bounded(8);
-        spawn_recorder_thread(command_rx);
-        Self { command_tx }
+        let suppression_active = Arc::new(AtomicBool::new(false));
+        let (level_tx, _) = broadcast::channel(LEVEL_CHANNEL_CAPACITY);
+        let (meter_tx, _) = broadcast::channel(METER_CHANNEL_CAPACITY);
+        let mic_sensitivity = Arc::new(AtomicU32::new(mic_sensitivity.to_bits()));
+        let silence_threshold = Arc::new(AtomicU32::new(silence_threshold.to_bits()));
+        spawn_recorder_thread(
+            command_rx,
+            suppression_active.clone(),
+            level_tx.clone(),
+            meter_tx.clone(),
+            mic_sensitivity.clone(),
+            silence_threshold.clone(),
+        );
+        Self {
+            command_tx,
+            suppression_active,
+            level_tx,
+            meter_tx,
+            mic_sensitivity,
+            silence_threshold,
+        }
+    }
+
+    /// Whether the real-time RNNoise stage is engaged for the in-progress recording, for
+    /// surfacing alongside `deepfilter_available` in `ServiceStatus`.
+    pub fn is_real_time_suppression_active(&self) -> bool {
+        self.suppression_active.load(Ordering::SeqCst)
+    }
+
+    /// Subscribe to live `AudioLevel` readings, for forwarding to the frontend as window events.
+    pub fn subscribe_levels(&self) -> broadcast::Receiver<AudioLevel> {
+        self.level_tx.subscribe()
+    }
+
+    /// Subscribe to live `MeterFrame` readings (VU levels in dBFS plus a decimated waveform
+    /// envelope), for forwarding to the frontend as window events.
+    pub fn subscribe_meters(&self) -> broadcast::Receiver<MeterFrame> {
+        self.meter_tx.subscribe()
     }
 
-    pub fn start(&self) -> Result<PathBuf> {
+    /// Set the linear gain multiplier applied before level computation. Clamped so a runaway
+    /// value can't make a quiet room read as clipping (or a loud one read as dead silence).
+    pub fn set_sensitivity(&self, gain: f32) {
+        self.mic_sensitivity
+            .store(gain.clamp(0.1, 8.0).to_bits(), Ordering::Relaxed);
+    }
+
+    /// Set the RMS level below which a window is considered silent.
+    pub fn set_silence_threshold(&self, threshold: f32) {
+        self.silence_threshold
+            .store(threshold.max(0.0).to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn start(
+        &self,
+        request: RecordingDeviceRequest,
+        preset: Option<AudioFilterPreset>,
+    ) -> Result<PathBuf> {
         let (resp_tx, resp_rx) = bounded(1);
         self.command_tx
-            .send(RecorderCommand::Start { resp: resp_tx })
+            .send(RecorderCommand::Start { request, preset, resp: resp_tx })
             .map_err(|_| anyhow!("Recorder thread unavailable"))?;
         resp_rx
             .recv()
             .map_err(|_| anyhow!("Recorder response channel closed"))?
     }
 
-    pub fn stop(&self) -> Result<PathBuf> {
+    /// List available audio input devices, for a device picker in the recording settings UI.
+    pub fn list_input_devices(&self) -> Result<Vec<InputDeviceInfo>> {
+        enumerate_input_devices()
+    }
+
+    /// Re-run an already-recorded WAV file through the preset DSP chain offline, without
+    /// involving a live device. See `reprocess_wav_with_preset` for details.
+    pub fn reprocess_recording(
+        &self,
+        input_path: &Path,
+        preset: Option<AudioFilterPreset>,
+    ) -> Result<PathBuf> {
+        reprocess_wav_with_preset(input_path, preset)
+    }
+
+    /// Stop recording. When `trim_silence` is set, regions that stayed below
+    /// `silence_threshold` for at least `MIN_SILENCE_DURATION_SECS` are cut from the output file.
+    /// Returns `Ok(None)` if the recording turned out to be empty or silent throughout (the
+    /// output file is deleted rather than left behind) instead of a real file's path.
+    pub fn stop(&self, trim_silence: bool) -> Result<Option<PathBuf>> {
         let (resp_tx, resp_rx) = bounded(1);
         self.command_tx
-            .send(RecorderCommand::Stop { resp: resp_tx })
+            .send(RecorderCommand::Stop { trim_silence, resp: resp_tx })
             .map_err(|_| anyhow!("Recorder thread unavailable"))?;
         resp_rx
             .recv()
@@ -83,12 +337,92 @@ impl NativeRecorderController {
     }
 }
 
+/// Tracks rolling RMS/peak for the live meter and, with hysteresis, which sample ranges stayed
+/// silent long enough to be worth trimming from the output file.
+struct LevelMeter {
+    window_size: usize,
+    window: Vec<f32>,
+    samples_seen: u64,
+    min_silence_samples: u64,
+    below_threshold: bool,
+    silence_run_start: Option<u64>,
+    silence_regions: Vec<(u64, u64)>,
+}
+
+impl LevelMeter {
+    fn new(sample_rate: u32) -> Self {
+        let window_size = ((sample_rate as f32 / LEVEL_EMIT_HZ) as usize).max(1);
+        let min_silence_samples = (sample_rate as f32 * MIN_SILENCE_DURATION_SECS) as u64;
+        Self {
+            window_size,
+            window: Vec::with_capacity(window_size),
+            samples_seen: 0,
+            min_silence_samples,
+            below_threshold: false,
+            silence_run_start: None,
+            silence_regions: Vec::new(),
+        }
+    }
+
+    /// Feed one gained sample; returns a fresh `AudioLevel` once a ~1/30s window has filled.
+    fn push(&mut self, gained_sample: f32, threshold: f32) -> Option<AudioLevel> {
+        self.window.push(gained_sample);
+        self.samples_seen += 1;
+
+        if self.window.len() < self.window_size {
+            return None;
+        }
+
+        let rms = (self.window.iter().map(|s| s * s).sum::<f32>() / self.window.len() as f32).sqrt();
+        let peak = self.window.iter().fold(0.0f32, |acc, s| acc.max(s.abs()));
+        self.window.clear();
+
+        let window_end = self.samples_seen;
+        let window_start = window_end - self.window_size as u64;
+
+        if rms < threshold {
+            if !self.below_threshold {
+                self.below_threshold = true;
+                self.silence_run_start = Some(window_start);
+            }
+        } else if rms > threshold * SILENCE_EXIT_MARGIN {
+            if let Some(start) = self.silence_run_start.take() {
+                if window_start - start >= self.min_silence_samples {
+                    self.silence_regions.push((start, window_start));
+                }
+            }
+            self.below_threshold = false;
+        }
+        // Between threshold and its exit margin: hold the current state (the hysteresis band).
+
+        Some(AudioLevel {
+            rms,
+            peak,
+            is_silent: self.below_threshold,
+        })
+    }
+
+    /// Close out any still-open silence run and return every region accumulated this recording.
+    fn finish(&mut self) -> Vec<(u64, u64)> {
+        if let Some(start) = self.silence_run_start.take() {
+            if self.samples_seen - start >= self.min_silence_samples {
+                self.silence_regions.push((start, self.samples_seen));
+            }
+        }
+        std::mem::take(&mut self.silence_regions)
+    }
+}
+
 struct NativeRecorder {
     stream: Option<cpal::Stream>,
     writer_handle: Option<thread::JoinHandle<Result<()>>>,
     stop_flag: Arc<AtomicBool>,
     pause_flag: Arc<AtomicBool>,
     output_path: Option<PathBuf>,
+    level_meter: Option<Arc<StdMutex<LevelMeter>>>,
+    meter_accum: Option<Arc<StdMutex<MeterAccumulator>>>,
+    sidecar_path: Option<PathBuf>,
+    recording_start: Option<SystemTime>,
     recording: bool,
 }
 
@@ -100,6 +434,10 @@ impl NativeRecorder {
             stop_flag: Arc::new(AtomicBool::new(false)),
             pause_flag: Arc::new(AtomicBool::new(false)),
             output_path: None,
+            level_meter: None,
+            meter_accum: None,
+            sidecar_path: None,
+            recording_start: None,
             recording: false,
         }
     }
@@ -108,7 +446,15 @@ impl NativeRecorder {
         self.recording
     }
 
-    fn start(&mut self) -> Result<PathBuf> {
+    fn start(
+        &mut self,
+        request: RecordingDeviceRequest,
+        preset: Option<AudioFilterPreset>,
+        level_tx: broadcast::Sender<AudioLevel>,
+        meter_tx: broadcast::Sender<MeterFrame>,
+        mic_sensitivity: Arc<AtomicU32>,
+        silence_threshold: Arc<AtomicU32>,
+    ) -> Result<PathBuf> {
         if self.recording {
             anyhow::bail!("Recording already in progress");
         }
@@ -124,18 +470,33 @@ impl NativeRecorder {
         let output_path = output_dir.join(format!("cogniscribe-recording-{}.wav", timestamp));
 
         let host = cpal::default_host();
-        let device = host
-            .default_input_device()
-            .context("No audio input device available")?;
+        let device = match &request.device_name {
+            Some(name) => host
+                .input_devices()
+                .context("Failed to enumerate input devices")?
+                .find(|d| d.name().map(|n| &n == name).unwrap_or(false))
+                .ok_or_else(|| anyhow!("Requested input device '{}' is no longer available", name))?,
+            None => host
+                .default_input_device()
+                .context("No audio input device available")?,
+        };
+
+        let device_name = device.name().unwrap_or_else(|_| "Unknown device".to_string());
 
         let default_config = device
             .default_input_config()
             .context("Failed to read default input config")?;
 
         let sample_format = default_config.sample_format();
-        let sample_rate = default_config.sample_rate().0;
-        let channels = default_config.channels() as usize;
-        let config: cpal::StreamConfig = default_config.into();
+        let mut config: cpal::StreamConfig = default_config.into();
+        if let Some(rate) = request.sample_rate {
+            config.sample_rate = cpal::SampleRate(rate);
+        }
+        if let Some(ch) = request.channels {
+            config.channels = ch;
+        }
+        let sample_rate = config.sample_rate.0;
+        let channels = config.channels as usize;
 
         let (sender, receiver) = bounded::<i16>(sample_rate as usize);
         let stop_flag = Arc::new(AtomicBool::new(false));
@@ -154,7 +515,11 @@ impl NativeRecorder {
 
         let err_fn = |err| eprintln!("Audio stream error: {}", err);
 
-        let mut pipeline = AudioPipeline::new();
+        let mut pipeline = AudioPipeline::new(sample_rate as f32, preset.as_ref());
+        let level_meter = Arc::new(StdMutex::new(LevelMeter::new(sample_rate)));
+        let level_meter_stream = level_meter.clone();
+        let meter_accum = Arc::new(StdMutex::new(MeterAccumulator::new(sample_rate)));
+        let meter_accum_stream = meter_accum.clone();
 
         let stream = match sample_format {
             SampleFormat::F32 => device.build_input_stream(
@@ -166,6 +531,12 @@ impl NativeRecorder {
                         &sender_stream,
                         &pause_flag_stream,
                         &mut pipeline,
+                        &level_meter_stream,
+                        &level_tx,
+                        &meter_accum_stream,
+                        &meter_tx,
+                        &mic_sensitivity,
+                        &silence_threshold,
                     )
                 },
                 err_fn,
@@ -180,6 +551,12 @@ impl NativeRecorder {
                         &sender_stream,
                         &pause_flag_stream,
                         &mut pipeline,
+                        &level_meter_stream,
+                        &level_tx,
+                        &meter_accum_stream,
+                        &meter_tx,
+                        &mic_sensitivity,
+                        &silence_threshold,
                     )
                 },
                 err_fn,
@@ -194,6 +571,12 @@ impl NativeRecorder {
                         &sender_stream,
                         &pause_flag_stream,
                         &mut pipeline,
+                        &level_meter_stream,
+                        &level_tx,
+                        &meter_accum_stream,
+                        &meter_tx,
+                        &mic_sensitivity,
+                        &silence_threshold,
                     )
                 },
                 err_fn,
@@ -204,17 +587,39 @@ impl NativeRecorder {
 
         stream.play().context("Failed to start audio stream")?;
 
+        let sidecar_path = sidecar_path_for(&output_path);
+        write_sidecar(
+            &sidecar_path,
+            &RecordingMetadata {
+                recording_id: Uuid::new_v4().to_string(),
+                start_time: Utc::now().to_rfc3339(),
+                end_time: None,
+                device_name,
+                sample_rate,
+                channels: channels as u16,
+                sample_format: format!("{:?}", sample_format),
+                preset,
+                duration_seconds: 0,
+                sample_count: 0,
+                bytes: 0,
+            },
+        )?;
+
         self.stream = Some(stream);
         self.writer_handle = Some(writer_handle);
         self.stop_flag = stop_flag;
         self.pause_flag = pause_flag;
         self.output_path = Some(output_path.clone());
+        self.level_meter = Some(level_meter);
+        self.meter_accum = Some(meter_accum);
+        self.sidecar_path = Some(sidecar_path);
+        self.recording_start = Some(SystemTime::now());
         self.recording = true;
 
         Ok(output_path)
     }
 
-    fn stop(&mut self) -> Result<PathBuf> {
+    fn stop(&mut self, trim_silence: bool) -> Result<Option<PathBuf>> {
         if !self.recording {
             anyhow::bail!("No recording in progress");
         }
@@ -224,18 +629,58 @@ impl NativeRecorder {
         // Drop the stream to stop callbacks
         self.stream.take();
 
-        if let Some(handle) = self.writer_handle.take() {
+        let kept = if let Some(handle) = self.writer_handle.take() {
             handle
                 .join()
-                .map_err(|_| anyhow::anyhow!("Failed to join writer thread"))??;
-        }
+                .map_err(|_| anyhow::anyhow!("Failed to join writer thread"))??
+        } else {
+            true
+        };
 
         self.recording = false;
         self.pause_flag.store(false, Ordering::SeqCst);
 
-        self.output_path
+        let output_path = self
+            .output_path
             .clone()
-            .context("Missing output path for recording")
+            .context("Missing output path for recording")?;
+
+        self.meter_accum.take();
+        let duration_seconds = self
+            .recording_start
+            .take()
+            .and_then(|start| start.elapsed().ok())
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+        let sidecar_path = self.sidecar_path.take();
+
+        if !kept {
+            self.level_meter.take();
+            if let Some(sidecar_path) = sidecar_path {
+                let _ = std::fs::remove_file(sidecar_path);
+            }
+            return Ok(None);
+        }
+
+        if trim_silence {
+            if let Some(level_meter) = self.level_meter.take() {
+                let silence_regions = level_meter
+                    .lock()
+                    .map_err(|_| anyhow!("Level meter lock poisoned"))?
+                    .finish();
+                if !silence_regions.is_empty() {
+                    trim_silence_from_wav(&output_path, &silence_regions)?;
+                }
+            }
+        } else {
+            self.level_meter.take();
+        }
+
+        if let Some(sidecar_path) = sidecar_path {
+            finalize_sidecar(&sidecar_path, &output_path, duration_seconds)?;
+        }
+
+        Ok(Some(output_path))
     }
 
     fn pause(&mut self) -> Result<()> {
@@ -255,16 +700,70 @@ impl NativeRecorder {
     }
 }
 
-fn spawn_recorder_thread(command_rx: Receiver<RecorderCommand>) {
+/// Rewrite `path` in place, dropping mono PCM16 sample ranges (`(start, end)`, end-exclusive)
+/// that the level meter marked as trimmable silence.
+fn trim_silence_from_wav(path: &Path, silence_regions: &[(u64, u64)]) -> Result<()> {
+    let mut reader = WavReader::open(path).context("Failed to reopen recording for trimming")?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader
+        .samples::<i16>()
+        .collect::<std::result::Result<_, _>>()
+        .context("Failed to read recorded samples for trimming")?;
+    drop(reader);
+
+    let trimmed: Vec<i16> = samples
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| {
+            let i = *i as u64;
+            !silence_regions
+                .iter()
+                .any(|(start, end)| i >= *start && i < *end)
+        })
+        .map(|(_, sample)| sample)
+        .collect();
+
+    let mut writer = WavWriter::create(path, spec)
+        .context("Failed to reopen recording for writing trimmed audio")?;
+    for sample in trimmed {
+        writer
+            .write_sample(sample)
+            .context("Failed to write trimmed sample")?;
+    }
+    writer
+        .finalize()
+        .context("Failed to finalize trimmed recording")?;
+
+    Ok(())
+}
+
+fn spawn_recorder_thread(
+    command_rx: Receiver<RecorderCommand>,
+    suppression_active: Arc<AtomicBool>,
+    level_tx: broadcast::Sender<AudioLevel>,
+    meter_tx: broadcast::Sender<MeterFrame>,
+    mic_sensitivity: Arc<AtomicU32>,
+    silence_threshold: Arc<AtomicU32>,
+) {
     thread::spawn(move || {
         let mut recorder = NativeRecorder::new();
         while let Ok(command) = command_rx.recv() {
             match command {
-                RecorderCommand::Start { resp } => {
-                    let _ = resp.send(recorder.start());
+                RecorderCommand::Start { request, preset, resp } => {
+                    let result = recorder.start(
+                        request,
+                        preset,
+                        level_tx.clone(),
+                        meter_tx.clone(),
+                        mic_sensitivity.clone(),
+                        silence_threshold.clone(),
+                    );
+                    suppression_active.store(result.is_ok(), Ordering::SeqCst);
+                    let _ = resp.send(result);
                 }
-                RecorderCommand::Stop { resp } => {
-                    let _ = resp.send(recorder.stop());
+                RecorderCommand::Stop { trim_silence, resp } => {
+                    suppression_active.store(false, Ordering::SeqCst);
+                    let _ = resp.send(recorder.stop(trim_silence));
                 }
                 RecorderCommand::Pause { resp } => {
                     let _ = resp.send(recorder.pause());
@@ -280,38 +779,60 @@ fn spawn_recorder_thread(command_rx: Receiver<RecorderCommand>) {
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn write_input_data_f32(
     input: &[f32],
     channels: usize,
     sender: &crossbeam_channel::Sender<i16>,
     pause_flag: &AtomicBool,
     pipeline: &mut AudioPipeline,
+    level_meter: &StdMutex<LevelMeter>,
+    level_tx: &broadcast::Sender<AudioLevel>,
+    meter_accum: &StdMutex<MeterAccumulator>,
+    meter_tx: &broadcast::Sender<MeterFrame>,
+    mic_sensitivity: &AtomicU32,
+    silence_threshold: &AtomicU32,
 ) {
     if pause_flag.load(Ordering::SeqCst) {
         return;
     }
 
+    let gain = f32::from_bits(mic_sensitivity.load(Ordering::Relaxed));
+    let threshold = f32::from_bits(silence_threshold.load(Ordering::Relaxed));
+
     for frame in input.chunks(channels) {
         let mut sum = 0.0f32;
         for sample in frame {
             sum += *sample;
         }
         let mono = sum / channels as f32;
-        push_sample(mono, sender, pipeline);
+        push_sample(
+            mono, sender, pipeline, level_meter, level_tx, meter_accum, meter_tx, gain, threshold,
+        );
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn write_input_data_i16(
     input: &[i16],
     channels: usize,
     sender: &crossbeam_channel::Sender<i16>,
     pause_flag: &AtomicBool,
     pipeline: &mut AudioPipeline,
+    level_meter: &StdMutex<LevelMeter>,
+    level_tx: &broadcast::Sender<AudioLevel>,
+    meter_accum: &StdMutex<MeterAccumulator>,
+    meter_tx: &broadcast::Sender<MeterFrame>,
+    mic_sensitivity: &AtomicU32,
+    silence_threshold: &AtomicU32,
 ) {
     if pause_flag.load(Ordering::SeqCst) {
         return;
     }
 
+    let gain = f32::from_bits(mic_sensitivity.load(Ordering::Relaxed));
+    let threshold = f32::from_bits(silence_threshold.load(Ordering::Relaxed));
+
     let scale = i16::MAX as f32;
     for frame in input.chunks(channels) {
         let mut sum = 0.0f32;
@@ -319,21 +840,33 @@ fn write_input_data_i16(
             sum += *sample as f32 / scale;
         }
         let mono = sum / channels as f32;
-        push_sample(mono, sender, pipeline);
+        push_sample(
+            mono, sender, pipeline, level_meter, level_tx, meter_accum, meter_tx, gain, threshold,
+        );
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn write_input_data_u16(
     input: &[u16],
     channels: usize,
     sender: &crossbeam_channel::Sender<i16>,
     pause_flag: &AtomicBool,
     pipeline: &mut AudioPipeline,
+    level_meter: &StdMutex<LevelMeter>,
+    level_tx: &broadcast::Sender<AudioLevel>,
+    meter_accum: &StdMutex<MeterAccumulator>,
+    meter_tx: &broadcast::Sender<MeterFrame>,
+    mic_sensitivity: &AtomicU32,
+    silence_threshold: &AtomicU32,
 ) {
     if pause_flag.load(Ordering::SeqCst) {
         return;
     }
 
+    let gain = f32::from_bits(mic_sensitivity.load(Ordering::Relaxed));
+    let threshold = f32::from_bits(silence_threshold.load(Ordering::Relaxed));
+
     let scale = u16::MAX as f32;
     for frame in input.chunks(channels) {
         let mut sum = 0.0f32;
@@ -342,26 +875,50 @@ fn write_input_data_u16(
             sum += normalized;
         }
         let mono = sum / channels as f32;
-        push_sample(mono, sender, pipeline);
+        push_sample(
+            mono, sender, pipeline, level_meter, level_tx, meter_accum, meter_tx, gain, threshold,
+        );
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn push_sample(
     mono: f32,
     sender: &crossbeam_channel::Sender<i16>,
     pipeline: &mut AudioPipeline,
+    level_meter: &StdMutex<LevelMeter>,
+    level_tx: &broadcast::Sender<AudioLevel>,
+    meter_accum: &StdMutex<MeterAccumulator>,
+    meter_tx: &broadcast::Sender<MeterFrame>,
+    gain: f32,
+    threshold: f32,
 ) {
+    // Sensitivity gain only feeds the meter/silence detector; the recorded signal is untouched.
+    let gained = (mono * gain).clamp(-1.0, 1.0);
+    if let Ok(mut meter) = level_meter.lock() {
+        if let Some(level) = meter.push(gained, threshold) {
+            let _ = level_tx.send(level);
+        }
+    }
+    if let Ok(mut accum) = meter_accum.lock() {
+        if let Some(frame) = accum.push(mono) {
+            let _ = meter_tx.send(frame);
+        }
+    }
+
     let processed = pipeline.process_sample(mono).clamp(-1.0, 1.0);
     let scaled = (processed * i16::MAX as f32) as i16;
     let _ = sender.try_send(scaled);
 }
 
+/// Returns `Ok(true)` if the recording had meaningful audio and was kept, or `Ok(false)` if it
+/// was empty/silent throughout and `output_path` was deleted instead.
 fn spawn_writer_thread(
     receiver: crossbeam_channel::Receiver<i16>,
     output_path: PathBuf,
     sample_rate: u32,
     stop_flag: Arc<AtomicBool>,
-) -> thread::JoinHandle<Result<()>> {
+) -> thread::JoinHandle<Result<bool>> {
     thread::spawn(move || {
         let spec = WavSpec {
             channels: 1,
@@ -373,6 +930,10 @@ fn spawn_writer_thread(
         let mut writer = WavWriter::create(&output_path, spec)
             .context("Failed to create WAV output file")?;
 
+        let silence_floor = i16::MAX as f32 * 10f32.powf(SILENCE_FLOOR_DBFS / 20.0);
+        let mut samples_written: u64 = 0;
+        let mut peak_amplitude: u16 = 0;
+
         loop {
             if stop_flag.load(Ordering::SeqCst) && receiver.is_empty() {
                 break;
@@ -383,6 +944,8 @@ fn spawn_writer_thread(
                     writer
                         .write_sample(sample)
                         .context("Failed to write audio sample")?;
+                    samples_written += 1;
+                    peak_amplitude = peak_amplitude.max(sample.unsigned_abs());
                 }
                 Err(RecvTimeoutError::Timeout) => {
                     if stop_flag.load(Ordering::SeqCst) {
@@ -394,10 +957,61 @@ fn spawn_writer_thread(
         }
 
         writer.finalize().context("Failed to finalize WAV file")?;
-        Ok(())
+
+        if samples_written == 0 || (peak_amplitude as f32) < silence_floor {
+            std::fs::remove_file(&output_path)
+                .context("Failed to delete empty/silent recording")?;
+            return Ok(false);
+        }
+
+        Ok(true)
     })
 }
 
+/// Enumerate every audio input device `cpal` can see, with its default config and supported
+/// sample formats, for the recording settings UI's device picker.
+fn enumerate_input_devices() -> Result<Vec<InputDeviceInfo>> {
+    let host = cpal::default_host();
+    let default_name = host.default_input_device().and_then(|d| d.name().ok());
+
+    let mut devices = Vec::new();
+    for device in host
+        .input_devices()
+        .context("Failed to enumerate input devices")?
+    {
+        let name = device.name().unwrap_or_else(|_| "Unknown device".to_string());
+
+        let (default_sample_rate, default_channels) = match device.default_input_config() {
+            Ok(cfg) => (cfg.sample_rate().0, cfg.channels()),
+            Err(_) => (0, 0),
+        };
+
+        let supported_sample_formats = device
+            .supported_input_configs()
+            .map(|configs| {
+                let mut formats: Vec<String> = configs
+                    .map(|cfg| format!("{:?}", cfg.sample_format()))
+                    .collect();
+                formats.sort();
+                formats.dedup();
+                formats
+            })
+            .unwrap_or_default();
+
+        let is_default = default_name.as_deref() == Some(name.as_str());
+
+        devices.push(InputDeviceInfo {
+            name,
+            is_default,
+            default_sample_rate,
+            default_channels,
+            supported_sample_formats,
+        });
+    }
+
+    Ok(devices)
+}
+
 fn default_recordings_dir() -> Result<PathBuf> {
     let base_dir = dirs::data_local_dir()
         .context("Failed to resolve data directory")?
@@ -405,3 +1019,84 @@ fn default_recordings_dir() -> Result<PathBuf> {
 
     Ok(base_dir.join("recordings"))
 }
+
+/// Generous upper bound on `AudioPipeline`'s internal frame-buffering latency (a single
+/// 480-sample hop today), used to flush every real sample back out of the pipeline after an
+/// offline reprocessing pass without having to depend on `processor`'s private frame size.
+const DSP_FLUSH_PADDING_SAMPLES: usize = 1024;
+
+/// Re-run an existing WAV file (captured live or otherwise) through the same `AudioPipeline`
+/// DSP chain `NativeRecorder::start` applies during live capture, using `preset`'s noise
+/// gate/suppression/compressor settings. Runs in a plain loop rather than against a live device,
+/// so it finishes faster than real time. The original file is left untouched; the processed
+/// audio is written to a new file alongside it and that new path is returned.
+pub fn reprocess_wav_with_preset(
+    input_path: &Path,
+    preset: Option<AudioFilterPreset>,
+) -> Result<PathBuf> {
+    let mut reader = WavReader::open(input_path).context("Failed to open recording for reprocessing")?;
+    let spec = reader.spec();
+    let channels = (spec.channels as usize).max(1);
+    let sample_rate = spec.sample_rate;
+
+    let raw_samples: Vec<i16> = reader
+        .samples::<i16>()
+        .collect::<std::result::Result<_, _>>()
+        .context("Failed to read recorded samples for reprocessing")?;
+
+    let mono: Vec<f32> = raw_samples
+        .chunks(channels)
+        .map(|frame| {
+            let sum: f32 = frame.iter().map(|s| *s as f32 / i16::MAX as f32).sum();
+            sum / channels as f32
+        })
+        .collect();
+
+    let mut pipeline = AudioPipeline::new(sample_rate as f32, preset.as_ref());
+    let mut processed: Vec<f32> = Vec::with_capacity(mono.len());
+    for sample in &mono {
+        processed.push(pipeline.process_sample(*sample));
+    }
+    // The pipeline buffers samples internally before emitting them; flush the tail of the last
+    // partial frame with silence so every real sample makes it into `processed`.
+    for _ in 0..DSP_FLUSH_PADDING_SAMPLES {
+        processed.push(pipeline.process_sample(0.0));
+    }
+    processed.truncate(mono.len());
+
+    let output_path = reprocessed_output_path(input_path);
+    let output_spec = WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: WavSampleFormat::Int,
+    };
+
+    let mut writer = WavWriter::create(&output_path, output_spec)
+        .context("Failed to create reprocessed WAV output file")?;
+    for sample in processed {
+        let scaled = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        writer
+            .write_sample(scaled)
+            .context("Failed to write reprocessed sample")?;
+    }
+    writer
+        .finalize()
+        .context("Failed to finalize reprocessed WAV file")?;
+
+    Ok(output_path)
+}
+
+/// `<name>.wav` -> `<name>-processed.wav`, alongside the original file.
+fn reprocessed_output_path(input_path: &Path) -> PathBuf {
+    let stem = input_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("recording");
+    let extension = input_path
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("wav");
+
+    input_path.with_file_name(format!("{}-processed.{}", stem, extension))
+}
@@ -0,0 +1,299 @@
+#![cfg(target_arch = "wasm32")]
+
+//! Browser counterpart to `recorder::NativeRecorderController`. cpal has no WebAudio host for
+//! `wasm32-unknown-unknown` — there's no upstream implementation of `cpal::traits::HostTrait` for
+//! this target at all — so capture here goes straight through `web-sys`/`wasm-bindgen` against the
+//! real browser APIs: `navigator.mediaDevices.getUserMedia` for the microphone stream and a
+//! `ScriptProcessorNode` tapped off a `MediaStreamAudioSourceNode` for per-buffer PCM callbacks.
+//! `wasm32-unknown-unknown` also has no real OS threads, so (as before) there's no dedicated
+//! writer thread: the `onaudioprocess` callback itself runs the DSP pipeline and accumulates
+//! samples into shared state guarded by a plain mutex, and there's no filesystem to write a WAV
+//! to, so `stop()` finalizes the recording in memory and hands the caller raw WAV bytes.
+//!
+//! Needs the `AudioContext`, `AudioDestinationNode`, `AudioNode`, `AudioProcessingEvent`,
+//! `MediaDevices`, `MediaStream`, `MediaStreamAudioSourceNode`, `MediaStreamConstraints`,
+//! `MediaStreamTrack`, `Navigator`, `ScriptProcessorNode` and `Window` `web-sys` features enabled,
+//! plus the `wasm-bindgen-futures` and `js-sys` crates (there's no Cargo.toml in this tree to
+//! declare any of that in, same as the other dependencies introduced without one).
+
+use std::io::Cursor;
+use std::sync::{Arc, Mutex as StdMutex};
+
+use anyhow::{anyhow, Context, Result};
+use hound::{SampleFormat as WavSampleFormat, WavSpec, WavWriter};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    AudioContext, AudioProcessingEvent, MediaStream, MediaStreamAudioSourceNode,
+    MediaStreamConstraints, MediaTrackConstraints, ScriptProcessorNode,
+};
+
+use super::recorder::RecordingDeviceRequest;
+use super::AudioPipeline;
+use crate::obs::AudioFilterPreset;
+
+/// Buffer size requested from the `ScriptProcessorNode`, in frames. 4096 (of the allowed powers
+/// of two from 256 to 16384) keeps the `onaudioprocess` callback infrequent enough not to starve
+/// the UI thread, at the cost of a little extra latency versus a native cpal callback.
+const SCRIPT_PROCESSOR_BUFFER_SIZE: u32 = 4096;
+
+struct WebRecorderState {
+    // Kept alive for the lifetime of the recording: dropping any of these tears down the graph
+    // and stops `onaudioprocess` from firing.
+    context: Option<AudioContext>,
+    media_stream: Option<MediaStream>,
+    source_node: Option<MediaStreamAudioSourceNode>,
+    processor_node: Option<ScriptProcessorNode>,
+    _on_audio_process: Option<Closure<dyn FnMut(AudioProcessingEvent)>>,
+    samples: Vec<i16>,
+    sample_rate: u32,
+    recording: bool,
+    paused: bool,
+}
+
+impl WebRecorderState {
+    fn new() -> Self {
+        Self {
+            context: None,
+            media_stream: None,
+            source_node: None,
+            processor_node: None,
+            _on_audio_process: None,
+            samples: Vec::new(),
+            sample_rate: 0,
+            recording: false,
+            paused: false,
+        }
+    }
+
+    fn teardown(&mut self) {
+        if let Some(node) = self.processor_node.take() {
+            let _ = node.disconnect();
+        }
+        if let Some(node) = self.source_node.take() {
+            let _ = node.disconnect();
+        }
+        if let Some(stream) = self.media_stream.take() {
+            for track in stream.get_tracks().iter() {
+                if let Ok(track) = track.dyn_into::<web_sys::MediaStreamTrack>() {
+                    track.stop();
+                }
+            }
+        }
+        if let Some(context) = self.context.take() {
+            let _ = context.close();
+        }
+        self._on_audio_process = None;
+    }
+}
+
+fn js_err(context: &str, err: JsValue) -> anyhow::Error {
+    anyhow!("{}: {}", context, js_sys::Error::from(err).message())
+}
+
+/// WebAudio-backed recorder for wasm32 builds. Mirrors `NativeRecorderController`'s public surface
+/// (`start`/`stop`/`pause`/`resume`/`is_recording`) so call sites read the same way, even though
+/// there's no shared trait: wasm32 and native builds never coexist in the same binary, and the
+/// two controllers' methods differ enough in return type (no filesystem here, so `stop` hands
+/// back raw WAV bytes instead of a path) that a unifying trait bought nothing but an extra enum.
+pub struct WebRecorderController {
+    state: Arc<StdMutex<WebRecorderState>>,
+}
+
+impl WebRecorderController {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(StdMutex::new(WebRecorderState::new())),
+        }
+    }
+
+    /// Request microphone access and start capturing. `getUserMedia` is asynchronous in the
+    /// browser, so unlike the native controller's synchronous `start` this has to be awaited;
+    /// it returns once permission is granted and the audio graph is wired up. The recording
+    /// itself isn't finished yet — callers get the actual WAV bytes from `stop()`.
+    pub async fn start(
+        &self,
+        request: RecordingDeviceRequest,
+        preset: Option<AudioFilterPreset>,
+    ) -> Result<()> {
+        {
+            let guard = self
+                .state
+                .lock()
+                .map_err(|_| anyhow!("Web recorder state poisoned"))?;
+            if guard.recording {
+                anyhow::bail!("Recording already in progress");
+            }
+        }
+
+        let window = web_sys::window().ok_or_else(|| anyhow!("Not running in a browser window"))?;
+        let context = AudioContext::new().map_err(|e| js_err("Failed to create AudioContext", e))?;
+        let sample_rate = context.sample_rate() as u32;
+
+        let media_devices = window
+            .navigator()
+            .media_devices()
+            .map_err(|e| js_err("mediaDevices is unavailable", e))?;
+        let mut constraints = MediaStreamConstraints::new();
+        if request.device_name.is_some() || request.sample_rate.is_some() || request.channels.is_some() {
+            let mut track_constraints = MediaTrackConstraints::new();
+            if let Some(device_id) = &request.device_name {
+                track_constraints.device_id(&JsValue::from_str(device_id));
+            }
+            if let Some(rate) = request.sample_rate {
+                track_constraints.sample_rate(&JsValue::from_f64(rate as f64));
+            }
+            if let Some(channels) = request.channels {
+                track_constraints.channel_count(&JsValue::from_f64(channels as f64));
+            }
+            constraints.audio(&track_constraints);
+        } else {
+            constraints.audio(&JsValue::TRUE);
+        }
+        let stream_promise = media_devices
+            .get_user_media_with_constraints(&constraints)
+            .map_err(|e| js_err("getUserMedia rejected synchronously", e))?;
+        let stream_js = JsFuture::from(stream_promise)
+            .await
+            .map_err(|e| js_err("Microphone permission was denied", e))?;
+        let media_stream: MediaStream = stream_js
+            .dyn_into()
+            .map_err(|_| anyhow!("getUserMedia did not return a MediaStream"))?;
+
+        let source_node = context
+            .create_media_stream_source(&media_stream)
+            .map_err(|e| js_err("Failed to create MediaStreamAudioSourceNode", e))?;
+        // One input channel (WebAudio mixes the stream down to it; only channel 0 is read below), one output channel — the
+        // output is never listened to, but WebAudio requires the node be connected to the
+        // destination for `onaudioprocess` to fire in most browsers.
+        let processor_node = context
+            .create_script_processor_with_buffer_size_and_number_of_input_channels_and_number_of_output_channels(
+                SCRIPT_PROCESSOR_BUFFER_SIZE,
+                1,
+                1,
+            )
+            .map_err(|e| js_err("Failed to create ScriptProcessorNode", e))?;
+
+        let mut pipeline = AudioPipeline::new(sample_rate as f32, preset.as_ref());
+        let state_callback = self.state.clone();
+        let on_audio_process = Closure::wrap(Box::new(move |event: AudioProcessingEvent| {
+            let Ok(input_buffer) = event.input_buffer() else {
+                return;
+            };
+            let mut channel = vec![0.0f32; input_buffer.length() as usize];
+            if input_buffer.copy_from_channel(&mut channel, 0).is_err() {
+                return;
+            }
+
+            let Ok(mut guard) = state_callback.lock() else {
+                return;
+            };
+            if !guard.recording || guard.paused {
+                return;
+            }
+            for sample in &channel {
+                let processed = pipeline.process_sample(*sample);
+                let clamped = (processed.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+                guard.samples.push(clamped);
+            }
+        }) as Box<dyn FnMut(AudioProcessingEvent)>);
+        processor_node.set_onaudioprocess(Some(on_audio_process.as_ref().unchecked_ref()));
+
+        source_node
+            .connect_with_audio_node(&processor_node)
+            .map_err(|e| js_err("Failed to connect source to processor", e))?;
+        processor_node
+            .connect_with_audio_node(&context.destination())
+            .map_err(|e| js_err("Failed to connect processor to destination", e))?;
+
+        let mut guard = self
+            .state
+            .lock()
+            .map_err(|_| anyhow!("Web recorder state poisoned"))?;
+        guard.samples.clear();
+        guard.sample_rate = sample_rate;
+        guard.recording = true;
+        guard.paused = false;
+        guard.context = Some(context);
+        guard.media_stream = Some(media_stream);
+        guard.source_node = Some(source_node);
+        guard.processor_node = Some(processor_node);
+        guard._on_audio_process = Some(on_audio_process);
+
+        Ok(())
+    }
+
+    pub fn stop(&self, _trim_silence: bool) -> Result<Option<Vec<u8>>> {
+        let mut guard = self
+            .state
+            .lock()
+            .map_err(|_| anyhow!("Web recorder state poisoned"))?;
+        if !guard.recording {
+            return Ok(None);
+        }
+        guard.teardown();
+        guard.recording = false;
+        guard.paused = false;
+
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: guard.sample_rate,
+            bits_per_sample: 16,
+            sample_format: WavSampleFormat::Int,
+        };
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut writer =
+                WavWriter::new(&mut cursor, spec).context("Failed to create in-memory WAV writer")?;
+            for sample in guard.samples.drain(..) {
+                writer
+                    .write_sample(sample)
+                    .context("Failed to write sample to in-memory WAV")?;
+            }
+            writer
+                .finalize()
+                .context("Failed to finalize in-memory WAV")?;
+        }
+
+        Ok(Some(cursor.into_inner()))
+    }
+
+    pub fn pause(&self) -> Result<()> {
+        let mut guard = self
+            .state
+            .lock()
+            .map_err(|_| anyhow!("Web recorder state poisoned"))?;
+        if !guard.recording {
+            anyhow::bail!("Not currently recording");
+        }
+        guard.paused = true;
+        Ok(())
+    }
+
+    pub fn resume(&self) -> Result<()> {
+        let mut guard = self
+            .state
+            .lock()
+            .map_err(|_| anyhow!("Web recorder state poisoned"))?;
+        if !guard.recording {
+            anyhow::bail!("Not currently recording");
+        }
+        guard.paused = false;
+        Ok(())
+    }
+
+    pub fn is_recording(&self) -> Result<bool> {
+        let guard = self
+            .state
+            .lock()
+            .map_err(|_| anyhow!("Web recorder state poisoned"))?;
+        Ok(guard.recording)
+    }
+}
+
+impl Default for WebRecorderController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -24,11 +25,60 @@ pub struct AppConfig {
 
     // UI preferences
     pub theme: String,
+
+    /// Whether `setup` should check GitHub releases for a newer CogniScribe build on launch
+    /// and emit `update-available` if one is found. Manual checks via the `check_for_update`
+    /// command bypass this flag.
     pub auto_updates: bool,
 
     // Recording settings
     pub recording_format: String,  // "wav" or "mp3"
     pub recording_device: String,  // Device ID or empty for default
+
+    // Remote/authenticated Ollama settings. When `ollama_api_url` is set, ProcessManager
+    // connects to it directly instead of spawning and port-scanning a bundled binary.
+    pub ollama_api_url: Option<String>,
+    pub ollama_bearer_token: Option<String>,
+
+    // How long to wait for a model warm-up generate() before treating it as still loading,
+    // and the context window to request for generation.
+    pub generate_timeout_secs: u64,
+    pub num_ctx: u32,
+
+    /// Client-side cap on Ollama requests per second. `<= 0` disables rate limiting entirely.
+    pub max_requests_per_second: f32,
+
+    // Embedding settings, used for semantic search over transcripts.
+    pub embedding_model: Option<String>,
+
+    // Whether CogniScribe (and its backend services) should launch automatically on login.
+    pub auto_launch: bool,
+
+    // Native recorder level metering. `mic_sensitivity` is a linear gain multiplier applied
+    // before level computation; `silence_threshold` is the RMS level below which a window is
+    // considered silent and eligible for trimming.
+    pub mic_sensitivity: f32,
+    pub silence_threshold: f32,
+
+    /// Versions of downloaded models/binaries last installed, keyed by name (e.g. `"ollama"`).
+    /// Compared against the latest GitHub release by `check_model_updates` to decide whether a
+    /// re-download is needed.
+    pub installed_versions: HashMap<String, String>,
+
+    // Service supervisor restart policy. The background health check polls every
+    // `service_health_interval_secs`; an unexpected exit triggers bounded exponential-backoff
+    // restarts, starting at `service_restart_base_delay_secs` and capped at
+    // `service_max_restart_attempts` tries before giving up.
+    pub service_max_restart_attempts: u32,
+    pub service_restart_base_delay_secs: u64,
+    pub service_health_interval_secs: u64,
+
+    /// Selected ASIO device name (from `obs_enumerate_asio_devices`) to route into the scene
+    /// collection's microphone source instead of the platform default capture device. `None`
+    /// means `obs_configure` uses `MicrophoneSource::Default`.
+    pub asio_device_name: Option<String>,
+    /// Input-channel-to-track routing for `asio_device_name`, ignored when it's `None`.
+    pub asio_channel_routes: Vec<crate::obs::AsioChannelRoute>,
 }
 
 impl Default for AppConfig {
@@ -47,6 +97,21 @@ impl Default for AppConfig {
             auto_updates: true,
             recording_format: "wav".to_string(),
             recording_device: String::new(),  // Empty = default device
+            ollama_api_url: None,
+            ollama_bearer_token: None,
+            generate_timeout_secs: 3,
+            num_ctx: 4096,
+            max_requests_per_second: 4.0,
+            embedding_model: Some("nomic-embed-text".to_string()),
+            auto_launch: false,
+            mic_sensitivity: 1.0,
+            silence_threshold: 0.02,
+            installed_versions: HashMap::new(),
+            service_max_restart_attempts: 5,
+            service_restart_base_delay_secs: 2,
+            service_health_interval_secs: 10,
+            asio_device_name: None,
+            asio_channel_routes: Vec::new(),
         }
     }
 }
@@ -138,6 +203,19 @@ mod tests {
         assert_eq!(config.auto_updates, true);
         assert_eq!(config.recording_format, "wav");
         assert_eq!(config.auto_delete_days, 7);
+        assert_eq!(config.ollama_api_url, None);
+        assert_eq!(config.ollama_bearer_token, None);
+        assert_eq!(config.generate_timeout_secs, 3);
+        assert_eq!(config.num_ctx, 4096);
+        assert_eq!(config.max_requests_per_second, 4.0);
+        assert_eq!(config.embedding_model, Some("nomic-embed-text".to_string()));
+        assert_eq!(config.auto_launch, false);
+        assert_eq!(config.mic_sensitivity, 1.0);
+        assert_eq!(config.silence_threshold, 0.02);
+        assert!(config.installed_versions.is_empty());
+        assert_eq!(config.service_max_restart_attempts, 5);
+        assert_eq!(config.service_restart_base_delay_secs, 2);
+        assert_eq!(config.service_health_interval_secs, 10);
     }
 
     #[test]
@@ -0,0 +1,99 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::model_downloader::ensure_model;
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingResponse {
+    embedding: Vec<f32>,
+}
+
+/// Embed `texts` via Ollama's `/api/embeddings`, returning one vector per input in order.
+///
+/// If `model` isn't pulled yet, it is provisioned through the same model-provisioning flow used
+/// for the chat model before the embed requests are issued. `num_ctx` is forwarded as
+/// `options.num_ctx` on each request, mirroring the generate requests in `process_manager` — Ollama
+/// has no API to query a model's max tokens, so a configurable default is the only lever callers
+/// have over the context window.
+pub async fn embed(
+    base_url: &str,
+    bearer_token: Option<&str>,
+    model: &str,
+    num_ctx: u32,
+    texts: &[String],
+) -> Result<Vec<Vec<f32>>> {
+    ensure_model(base_url, bearer_token, model, |_progress| {}).await?;
+
+    let client = reqwest::Client::new();
+    let mut embeddings = Vec::with_capacity(texts.len());
+
+    for text in texts {
+        let mut request = client.post(format!("{}/api/embeddings", base_url)).json(&serde_json::json!({
+            "model": model,
+            "prompt": text,
+            "options": { "num_ctx": num_ctx }
+        }));
+        if let Some(token) = bearer_token {
+            request = request.bearer_auth(token);
+        }
+
+        let response: EmbeddingResponse = request
+            .send()
+            .await
+            .context("Failed to request Ollama embedding")?
+            .json()
+            .await
+            .context("Failed to parse Ollama embedding response")?;
+
+        embeddings.push(response.embedding);
+    }
+
+    if let Some(first) = embeddings.first() {
+        println!(
+            "Embedded {} text(s) with {} ({}-dimensional vectors)",
+            embeddings.len(),
+            model,
+            first.len()
+        );
+    }
+
+    Ok(embeddings)
+}
+
+/// Cosine similarity between two equal-length embedding vectors, in `[-1.0, 1.0]`.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosine_similarity_identical_vectors() {
+        let v = vec![1.0, 2.0, 3.0];
+        assert!((cosine_similarity(&v, &v) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cosine_similarity_orthogonal_vectors() {
+        let a = vec![1.0, 0.0];
+        let b = vec![0.0, 1.0];
+        assert!(cosine_similarity(&a, &b).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cosine_similarity_zero_vector_is_zero() {
+        let a = vec![0.0, 0.0];
+        let b = vec![1.0, 1.0];
+        assert_eq!(cosine_similarity(&a, &b), 0.0);
+    }
+}
@@ -6,20 +6,29 @@ mod config;
 mod model_downloader;
 mod obs;
 mod audio;
+mod rate_limiter;
+mod embeddings;
+mod supervisor;
+mod updater;
 
+use std::path::Path;
+use auto_launch::AutoLaunchBuilder;
 use tokio::sync::Mutex;
 use tauri::{Manager, State};
 use process_manager::{ProcessManager, ServiceStatus};
 use config::{AppConfig, load_config, save_config};
-use model_downloader::{download_whisper_model, download_ollama_model, DownloadProgress, are_bundled_models_installed};
-use obs::{OBSDetector, OBSManager, OBSInfo, OBSConnectionStatus, OBSAudioSource, OBSRecordingStatus, AudioFilterPreset};
-use audio::NativeRecorderController;
+use model_downloader::{download_whisper_model, download_ollama_model, list_installed_models, DownloadProgress, ModelInfo, ModelUpdateStatus, are_bundled_models_installed};
+use obs::{OBSDetector, OBSManager, OBSInfo, OBSConnectionStatus, OBSAudioSource, OBSRecordingStatus, AudioFilterPreset, ObsControlClient, ObsConnectionState, SceneInfo, RecordingProfile, ConnectionProfile, OBSStreamingStatus, OBSReplayBufferStatus, OBSSceneCollection, OBSProfile, OBSTransition};
+use audio::{NativeRecorderController, InputDeviceInfo, RecordingDeviceRequest};
+use supervisor::ServiceSupervisor;
+use updater::UpdateInfo;
 
 // Application state
-struct AppState {
-    process_manager: Mutex<ProcessManager>,
-    config: Mutex<AppConfig>,
+pub(crate) struct AppState {
+    pub(crate) process_manager: Mutex<ProcessManager>,
+    pub(crate) config: Mutex<AppConfig>,
     obs_manager: Mutex<OBSManager>,
+    obs_control: Mutex<Option<ObsControlClient>>,
     native_recorder: NativeRecorderController,
 }
 
@@ -30,12 +39,48 @@ async fn is_first_run(state: State<'_, AppState>) -> Result<bool, String> {
     Ok(!config.setup_completed)
 }
 
+/// Enable or disable launching CogniScribe on login, without creating duplicate registry/plist
+/// entries if the current state already matches `enable`.
+fn apply_auto_launch(enable: bool) -> Result<(), String> {
+    let app_path = std::env::current_exe().map_err(|e| e.to_string())?;
+    let auto = AutoLaunchBuilder::new()
+        .set_app_name("CogniScribe")
+        .set_app_path(&app_path.to_string_lossy())
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let currently_enabled = auto.is_enabled().map_err(|e| e.to_string())?;
+    if currently_enabled == enable {
+        return Ok(());
+    }
+
+    if enable {
+        auto.enable().map_err(|e| e.to_string())?;
+    } else {
+        auto.disable().map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Enable or disable start-on-login and persist the choice to `AppConfig`.
+#[tauri::command]
+async fn set_auto_launch(enable: bool, state: State<'_, AppState>) -> Result<(), String> {
+    apply_auto_launch(enable)?;
+
+    let mut config = state.config.lock().await;
+    config.auto_launch = enable;
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 /// Mark setup as completed
 #[tauri::command]
 async fn complete_setup(state: State<'_, AppState>) -> Result<(), String> {
     let mut config = state.config.lock().await;
     config.setup_completed = true;
     save_config(&config).map_err(|e| e.to_string())?;
+    apply_auto_launch(config.auto_launch)?;
     Ok(())
 }
 
@@ -52,13 +97,19 @@ async fn update_config(
     state: State<'_, AppState>,
     new_config: AppConfig
 ) -> Result<(), String> {
+    state.native_recorder.set_sensitivity(new_config.mic_sensitivity);
+    state
+        .native_recorder
+        .set_silence_threshold(new_config.silence_threshold);
+
     let mut config = state.config.lock().await;
     *config = new_config;
     save_config(&config).map_err(|e| e.to_string())?;
     Ok(())
 }
 
-/// Start backend services (Ollama + Python API)
+/// Start backend services (Ollama + Python API), then start the background supervisor that
+/// watches for an unexpected exit and auto-restarts with backoff.
 #[tauri::command]
 async fn start_services(
     state: State<'_, AppState>,
@@ -77,13 +128,26 @@ async fn start_services(
     manager.start_all(&resource_dir, &config)
         .await
         .map_err(|e| e.to_string())?;
+    drop(manager);
+
+    app_handle
+        .state::<Mutex<ServiceSupervisor>>()
+        .lock()
+        .await
+        .start(app_handle.clone(), resource_dir);
 
     Ok(())
 }
 
-/// Stop backend services
+/// Stop the supervisor first so it doesn't race a restart against this intentional shutdown,
+/// then stop backend services
 #[tauri::command]
-async fn stop_services(state: State<'_, AppState>) -> Result<(), String> {
+async fn stop_services(
+    state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    app_handle.state::<Mutex<ServiceSupervisor>>().lock().await.stop();
+
     let mut manager = state.process_manager.lock().await;
     manager.stop_all().await.map_err(|e| e.to_string())?;
     Ok(())
@@ -92,14 +156,20 @@ async fn stop_services(state: State<'_, AppState>) -> Result<(), String> {
 /// Get status of all services
 #[tauri::command]
 async fn get_service_status(state: State<'_, AppState>) -> Result<ServiceStatus, String> {
-    let manager = state.process_manager.lock().await;
-    Ok(manager.get_status())
+    let config = state.config.lock().await.clone();
+    let mut manager = state.process_manager.lock().await;
+    let mut status = manager.get_status(&config).await;
+    status.realtime_suppression_active = state.native_recorder.is_real_time_suppression_active();
+    Ok(status)
 }
 
-/// Download a model with progress tracking
+/// Download a model with progress tracking. `model_name` only applies to `model_type == "llama"`
+/// and selects which Ollama model to pull (e.g. `"llama3.1:8b"`, `"qwen2.5:3b"`).
 #[tauri::command]
 async fn download_model(
     model_type: String,
+    model_name: Option<String>,
+    state: State<'_, AppState>,
     app_handle: tauri::AppHandle
 ) -> Result<(), String> {
     let resource_dir = app_handle
@@ -118,7 +188,9 @@ async fn download_model(
                 .map_err(|e| e.to_string())?;
         }
         "llama" => {
-            download_ollama_model(progress_callback)
+            let model_name = model_name.ok_or("model_name is required for model_type \"llama\"")?;
+            let (base_url, bearer_token) = state.process_manager.lock().await.ollama_connection();
+            download_ollama_model(&base_url, bearer_token.as_deref(), &model_name, progress_callback)
                 .await
                 .map_err(|e| e.to_string())?;
         }
@@ -128,6 +200,77 @@ async fn download_model(
     Ok(())
 }
 
+/// List models installed on the configured Ollama server, for the model picker UI. Also doubles
+/// as a liveness check: callers can treat a failure here as "Ollama isn't reachable".
+#[tauri::command]
+async fn list_ollama_models(state: State<'_, AppState>) -> Result<Vec<ModelInfo>, String> {
+    let (base_url, bearer_token) = state.process_manager.lock().await.ollama_connection();
+    list_installed_models(&base_url, bearer_token.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Compare installed model/binary versions against the latest available release, for the setup
+/// UI to surface an "update available" badge without forcing a redownload.
+#[tauri::command]
+async fn check_model_updates(state: State<'_, AppState>) -> Result<Vec<ModelUpdateStatus>, String> {
+    let installed_versions = state.config.lock().await.installed_versions.clone();
+    model_downloader::check_model_updates(&installed_versions)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Check GitHub for a CogniScribe build newer than the one currently running. Also called from
+/// `setup` on launch when `auto_updates` is enabled.
+#[tauri::command]
+async fn check_for_update(app_handle: tauri::AppHandle) -> Result<Option<UpdateInfo>, String> {
+    let current_version = app_handle.package_info().version.to_string();
+    updater::check_for_update(&current_version)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Download and verify a previously-discovered update, staging it for `install_update`. Emits
+/// `update-progress` events reusing the model-download `DownloadProgress` shape.
+#[tauri::command]
+async fn download_update(app_handle: tauri::AppHandle, update: UpdateInfo) -> Result<String, String> {
+    let staging_dir = app_handle
+        .path_resolver()
+        .app_cache_dir()
+        .ok_or("Failed to get cache directory")?
+        .join("updates");
+
+    let emitter = app_handle.clone();
+    let staged_path = updater::download_update(&staging_dir, &update, move |progress| {
+        let _ = emitter.emit_all("update-progress", progress);
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(staged_path.to_string_lossy().to_string())
+}
+
+/// Stop backend services and disconnect OBS so nothing survives as an orphan, apply a verified
+/// staged update package, emit `update-ready`, then relaunch. The current install is left
+/// intact if `staged_path` was never produced by a successful `download_update` call.
+#[tauri::command]
+async fn install_update(
+    state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+    staged_path: String,
+) -> Result<(), String> {
+    app_handle.state::<Mutex<ServiceSupervisor>>().lock().await.stop();
+    state.process_manager.lock().await.stop_all().await.map_err(|e| e.to_string())?;
+    state.obs_manager.lock().await.disconnect().await.map_err(|e| e.to_string())?;
+
+    updater::install_update(Path::new(&staged_path))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let _ = app_handle.emit_all("update-ready", ());
+    tauri::api::process::restart(&app_handle.env())
+}
+
 /// Check backend health
 #[tauri::command]
 async fn check_backend_health() -> Result<serde_json::Value, String> {
@@ -166,26 +309,126 @@ async fn save_recorded_audio(path: String, audio_data: Vec<u8>) -> Result<(), St
 
 // ==================== In-App Recording Commands ====================
 
-/// Start native in-app recording (studio pipeline).
+/// Start native in-app recording (studio pipeline). `device_name` selects a specific input
+/// device (falling back to the platform default when omitted); `sample_rate`/`channels`
+/// override the device's own defaults when the caller wants a specific capture format.
+/// `preset_name`, when given, builds a real-time noise-gate/compressor DSP chain from the named
+/// `AudioFilterPreset` (see `obs_apply_filter_preset` for the same name-to-preset mapping).
 #[tauri::command]
-fn native_start_recording(state: State<'_, AppState>) -> Result<String, String> {
+fn native_start_recording(
+    device_name: Option<String>,
+    sample_rate: Option<u32>,
+    channels: Option<u16>,
+    preset_name: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let preset = match preset_name.as_deref() {
+        Some("lecture_hall") => Some(AudioFilterPreset::lecture_hall()),
+        Some("clinical_skills") => Some(AudioFilterPreset::clinical_skills()),
+        Some("online_lecture") => Some(AudioFilterPreset::online_lecture()),
+        Some(_) => return Err("Unknown preset".to_string()),
+        None => None,
+    };
+
     state
         .native_recorder
-        .start()
+        .start(RecordingDeviceRequest { device_name, sample_rate, channels }, preset)
         .map(|path| path.to_string_lossy().to_string())
         .map_err(|e| e.to_string())
 }
 
-/// Stop native in-app recording and return the output file path.
+/// List available audio input devices, for a device picker in the recording settings UI.
+#[tauri::command]
+fn native_list_input_devices(state: State<'_, AppState>) -> Result<Vec<InputDeviceInfo>, String> {
+    state.native_recorder.list_input_devices().map_err(|e| e.to_string())
+}
+
+/// Re-run an already-captured WAV file through the preset DSP chain offline (no audio device
+/// involved), for when enhancement is decided after the fact instead of at capture time. The
+/// original file is left untouched; the new processed file's path is returned.
 #[tauri::command]
-fn native_stop_recording(state: State<'_, AppState>) -> Result<String, String> {
+fn native_reprocess_recording(
+    input_path: String,
+    preset_name: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let preset = match preset_name.as_deref() {
+        Some("lecture_hall") => Some(AudioFilterPreset::lecture_hall()),
+        Some("clinical_skills") => Some(AudioFilterPreset::clinical_skills()),
+        Some("online_lecture") => Some(AudioFilterPreset::online_lecture()),
+        Some(_) => return Err("Unknown preset".to_string()),
+        None => None,
+    };
+
     state
         .native_recorder
-        .stop()
+        .reprocess_recording(Path::new(&input_path), preset)
         .map(|path| path.to_string_lossy().to_string())
         .map_err(|e| e.to_string())
 }
 
+/// Stop native in-app recording and return the output file path. When `trim_silence` is true,
+/// regions the level meter marked as silent for long enough are cut from the output file.
+/// Returns `None` (instead of an error) if the recording was empty or silent throughout and was
+/// discarded rather than left on disk as a useless file.
+#[tauri::command]
+fn native_stop_recording(trim_silence: bool, state: State<'_, AppState>) -> Result<Option<String>, String> {
+    state
+        .native_recorder
+        .stop(trim_silence)
+        .map(|maybe_path| maybe_path.map(|path| path.to_string_lossy().to_string()))
+        .map_err(|e| e.to_string())
+}
+
+/// Set the linear gain multiplier applied before level computation, and persist it.
+#[tauri::command]
+async fn native_set_sensitivity(gain: f32, state: State<'_, AppState>) -> Result<(), String> {
+    state.native_recorder.set_sensitivity(gain);
+
+    let mut config = state.config.lock().await;
+    config.mic_sensitivity = gain.clamp(0.1, 8.0);
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Start relaying live `AudioLevel` readings to the frontend as `audio-level` window events, for
+/// driving a VU meter while `native_start_recording` is active.
+#[tauri::command]
+async fn native_subscribe_audio_level(
+    state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let mut rx = state.native_recorder.subscribe_levels();
+
+    tauri::async_runtime::spawn(async move {
+        while let Ok(level) = rx.recv().await {
+            let _ = app_handle.emit_all("audio-level", level);
+        }
+    });
+
+    Ok(())
+}
+
+/// Start relaying live `MeterFrame` readings to the frontend as `meter-frame` window events, for
+/// driving a VU meter / scrolling waveform while `native_start_recording` is active. Distinct
+/// from `native_subscribe_audio_level`: this is dBFS plus a decimated waveform envelope for a
+/// scrolling display rather than a single VU needle, sent every ~50ms (~20Hz) instead of ~30Hz.
+#[tauri::command]
+async fn native_subscribe_meter_frames(
+    state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let mut rx = state.native_recorder.subscribe_meters();
+
+    tauri::async_runtime::spawn(async move {
+        while let Ok(frame) = rx.recv().await {
+            let _ = app_handle.emit_all("meter-frame", frame);
+        }
+    });
+
+    Ok(())
+}
+
 /// Pause native recording without stopping the stream.
 #[tauri::command]
 fn native_pause_recording(state: State<'_, AppState>) -> Result<(), String> {
@@ -235,6 +478,24 @@ async fn obs_connect(
         .map_err(|e| e.to_string())
 }
 
+/// Connect to OBS using a named connection profile loaded from `obs.toml`
+#[tauri::command]
+async fn obs_connect_with_profile(
+    state: State<'_, AppState>,
+    profile_name: String,
+) -> Result<OBSConnectionStatus, String> {
+    let mut obs = state.obs_manager.lock().await;
+    obs.connect_with_profile(&profile_name)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// List the named connection profiles defined in `obs.toml`
+#[tauri::command]
+async fn obs_list_connection_profiles() -> Result<Vec<ConnectionProfile>, String> {
+    obs::profiles::load_connection_profiles().map_err(|e| e.to_string())
+}
+
 /// Disconnect from OBS
 #[tauri::command]
 async fn obs_disconnect(state: State<'_, AppState>) -> Result<(), String> {
@@ -249,6 +510,48 @@ async fn obs_is_connected(state: State<'_, AppState>) -> Result<bool, String> {
     Ok(obs.is_connected())
 }
 
+/// Connect to OBS WebSocket with automatic heartbeat-driven reconnection.
+#[tauri::command]
+async fn obs_connect_resilient(
+    state: State<'_, AppState>,
+    host: String,
+    port: u16,
+    password: Option<String>,
+    max_reconnect_attempts: u32,
+) -> Result<OBSConnectionStatus, String> {
+    let mut obs = state.obs_manager.lock().await;
+    obs.connect_resilient(&host, port, password, max_reconnect_attempts)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the current resilient-connection state (connected / reconnecting / disconnected).
+#[tauri::command]
+async fn obs_connection_state(state: State<'_, AppState>) -> Result<ObsConnectionState, String> {
+    let obs = state.obs_manager.lock().await;
+    Ok(obs.connection_state())
+}
+
+/// Start relaying OBS events (recording state changes, mutes, disconnects) to the frontend as
+/// `obs-event` window events, so the UI reacts immediately instead of only on the next poll.
+#[tauri::command]
+async fn obs_subscribe_events(
+    state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let mut obs = state.obs_manager.lock().await;
+    let mut rx = obs.subscribe_events().await.map_err(|e| e.to_string())?;
+    drop(obs);
+
+    tauri::async_runtime::spawn(async move {
+        while let Ok(event) = rx.recv().await {
+            let _ = app_handle.emit_all("obs-event", event);
+        }
+    });
+
+    Ok(())
+}
+
 /// Get list of audio sources from OBS
 #[tauri::command]
 async fn obs_get_audio_sources(state: State<'_, AppState>) -> Result<Vec<OBSAudioSource>, String> {
@@ -346,12 +649,150 @@ async fn obs_set_source_muted(
         .map_err(|e| e.to_string())
 }
 
+/// List all scenes in the current OBS scene collection
+#[tauri::command]
+async fn obs_list_scenes(state: State<'_, AppState>) -> Result<Vec<SceneInfo>, String> {
+    let obs = state.obs_manager.lock().await;
+    obs.list_scenes().await.map_err(|e| e.to_string())
+}
+
+/// Get the current program scene
+#[tauri::command]
+async fn obs_current_scene(state: State<'_, AppState>) -> Result<String, String> {
+    let obs = state.obs_manager.lock().await;
+    obs.current_scene().await.map_err(|e| e.to_string())
+}
+
+/// Switch the current program scene, optionally via a named transition
+#[tauri::command]
+async fn obs_set_scene(
+    state: State<'_, AppState>,
+    scene_name: String,
+    transition_name: Option<String>,
+    transition_duration_ms: Option<u32>,
+) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.set_scene(&scene_name, transition_name.as_deref(), transition_duration_ms)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// List all scene collections known to OBS
+#[tauri::command]
+async fn obs_list_scene_collections(state: State<'_, AppState>) -> Result<Vec<OBSSceneCollection>, String> {
+    let obs = state.obs_manager.lock().await;
+    obs.list_scene_collections().await.map_err(|e| e.to_string())
+}
+
+/// Switch OBS to a different scene collection
+#[tauri::command]
+async fn obs_set_scene_collection(state: State<'_, AppState>, name: String) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.set_scene_collection(&name).await.map_err(|e| e.to_string())
+}
+
+/// List all OBS profiles
+#[tauri::command]
+async fn obs_list_profiles(state: State<'_, AppState>) -> Result<Vec<OBSProfile>, String> {
+    let obs = state.obs_manager.lock().await;
+    obs.list_profiles().await.map_err(|e| e.to_string())
+}
+
+/// Switch OBS to a different profile
+#[tauri::command]
+async fn obs_set_profile(state: State<'_, AppState>, name: String) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.set_profile(&name).await.map_err(|e| e.to_string())
+}
+
+/// List all scene transitions available in the current scene collection
+#[tauri::command]
+async fn obs_list_transitions(state: State<'_, AppState>) -> Result<Vec<OBSTransition>, String> {
+    let obs = state.obs_manager.lock().await;
+    obs.list_transitions().await.map_err(|e| e.to_string())
+}
+
+/// Select the scene transition used for subsequent scene switches
+#[tauri::command]
+async fn obs_set_transition(state: State<'_, AppState>, name: String) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.set_transition(&name).await.map_err(|e| e.to_string())
+}
+
+/// Switch to a recording profile's scene and reapply its filter preset/volume in one step
+#[tauri::command]
+async fn obs_apply_recording_profile(
+    state: State<'_, AppState>,
+    profile: RecordingProfile,
+) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.apply_recording_profile(&profile)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Start streaming to the destination configured in OBS
+#[tauri::command]
+async fn obs_start_streaming(state: State<'_, AppState>) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.start_streaming().await.map_err(|e| e.to_string())
+}
+
+/// Stop streaming
+#[tauri::command]
+async fn obs_stop_streaming(state: State<'_, AppState>) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.stop_streaming().await.map_err(|e| e.to_string())
+}
+
+/// Get current streaming status
+#[tauri::command]
+async fn obs_streaming_status(state: State<'_, AppState>) -> Result<OBSStreamingStatus, String> {
+    let obs = state.obs_manager.lock().await;
+    obs.streaming_status().await.map_err(|e| e.to_string())
+}
+
+/// Start the replay buffer
+#[tauri::command]
+async fn obs_start_replay_buffer(state: State<'_, AppState>) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.start_replay_buffer().await.map_err(|e| e.to_string())
+}
+
+/// Stop the replay buffer
+#[tauri::command]
+async fn obs_stop_replay_buffer(state: State<'_, AppState>) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.stop_replay_buffer().await.map_err(|e| e.to_string())
+}
+
+/// Get whether the replay buffer is currently active
+#[tauri::command]
+async fn obs_replay_buffer_status(state: State<'_, AppState>) -> Result<OBSReplayBufferStatus, String> {
+    let obs = state.obs_manager.lock().await;
+    obs.replay_buffer_status().await.map_err(|e| e.to_string())
+}
+
+/// Save the last N seconds of the replay buffer to disk
+#[tauri::command]
+async fn obs_save_replay(state: State<'_, AppState>) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.save_replay().await.map_err(|e| e.to_string())
+}
+
+/// Split the current recording into a new file without stopping capture (OBS 28+)
+#[tauri::command]
+async fn obs_split_recording(state: State<'_, AppState>) -> Result<(), String> {
+    let obs = state.obs_manager.lock().await;
+    obs.split_recording().await.map_err(|e| e.to_string())
+}
+
 /// Download and install OBS Studio automatically
 #[tauri::command]
 async fn obs_download_and_install(
     app_handle: tauri::AppHandle
 ) -> Result<(), String> {
-    use obs::{OBSInstaller, OBSInstallProgress};
+    use obs::{ExpectedArtifact, OBSInstaller, OBSInstallProgress};
 
     let downloads_dir = app_handle
         .path_resolver()
@@ -363,20 +804,51 @@ async fn obs_download_and_install(
         let _ = app_handle.emit_all("obs-install-progress", progress);
     };
 
-    OBSInstaller::install_and_configure(&downloads_dir, progress_callback)
-        .await
-        .map_err(|e| e.to_string())
+    // `install_and_configure_verified` resolves the latest GitHub release itself and fills in
+    // `expected.sha256` from the release's published checksum when we don't pin one here, so the
+    // download is actually checked before it's run as an installer rather than trusted blindly.
+    OBSInstaller::install_and_configure_verified(
+        &downloads_dir,
+        &ExpectedArtifact::default(),
+        progress_callback,
+    )
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// List the ASIO device names installed on this machine, for routing a multi-channel audio
+/// interface's inputs into the scene collection.
+#[tauri::command]
+async fn obs_enumerate_asio_devices() -> Result<Vec<String>, String> {
+    obs::asio::enumerate_asio_devices().map_err(|e| e.to_string())
 }
 
-/// Configure OBS Studio settings
+/// Configure OBS Studio settings, routing the microphone source through the ASIO device and
+/// channel routes configured via `update_config` (if any), or the platform default device
+/// otherwise. Returns the generated WebSocket password.
 #[tauri::command]
-async fn obs_configure() -> Result<(), String> {
-    use obs::OBSConfigWriter;
+async fn obs_configure(state: State<'_, AppState>) -> Result<String, String> {
+    use obs::{MicrophoneSource, OBSConfigWriter};
 
-    OBSConfigWriter::configure_all()
+    let config = state.config.lock().await.clone();
+    let mic_source = match config.asio_device_name {
+        Some(device_name) => MicrophoneSource::Asio {
+            device_name,
+            routes: config.asio_channel_routes,
+        },
+        None => MicrophoneSource::Default,
+    };
+
+    OBSConfigWriter::configure_all(&mic_source)
         .map_err(|e| e.to_string())
 }
 
+/// Read back the WebSocket password `obs_configure` previously generated
+#[tauri::command]
+async fn obs_get_generated_password() -> Result<Option<String>, String> {
+    obs::config_writer::read_generated_password().map_err(|e| e.to_string())
+}
+
 /// Launch OBS Studio
 #[tauri::command]
 async fn obs_launch() -> Result<(), String> {
@@ -395,41 +867,173 @@ async fn obs_get_download_url() -> Result<String, String> {
         .map_err(|e| e.to_string())
 }
 
+// ==================== OBS WebSocket Control Commands ====================
+
+/// Connect the raw obs-websocket control client (used for start/stop-record after install).
+#[tauri::command]
+async fn obs_control_connect(
+    state: State<'_, AppState>,
+    host: String,
+    port: u16,
+    password: Option<String>,
+) -> Result<(), String> {
+    let client = ObsControlClient::connect(&host, port, password.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+    *state.obs_control.lock().await = Some(client);
+    Ok(())
+}
+
+/// Connect the raw obs-websocket control client with automatic reconnection on disconnect.
+#[tauri::command]
+async fn obs_control_connect_resilient(
+    state: State<'_, AppState>,
+    host: String,
+    port: u16,
+    password: Option<String>,
+    max_reconnect_attempts: u32,
+) -> Result<(), String> {
+    let client = ObsControlClient::connect_resilient(&host, port, password.as_deref(), max_reconnect_attempts)
+        .await
+        .map_err(|e| e.to_string())?;
+    *state.obs_control.lock().await = Some(client);
+    Ok(())
+}
+
+/// Get the OBS/WebSocket version via the raw obs-websocket control client.
+#[tauri::command]
+async fn obs_control_get_version(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let guard = state.obs_control.lock().await;
+    let client = guard.as_ref().ok_or("Not connected to OBS WebSocket")?;
+    client.get_version().await.map_err(|e| e.to_string())
+}
+
+/// Apply CogniScribe's "Lecture Hall" filter chain to a source via the raw obs-websocket
+/// control client, so the preset takes effect immediately instead of requiring an OBS restart.
+#[tauri::command]
+async fn obs_control_apply_lecture_hall_filters(
+    state: State<'_, AppState>,
+    source_name: String,
+) -> Result<(), String> {
+    let guard = state.obs_control.lock().await;
+    let client = guard.as_ref().ok_or("Not connected to OBS WebSocket")?;
+    client.apply_lecture_hall_filters(&source_name).await.map_err(|e| e.to_string())
+}
+
+/// Start recording via the raw obs-websocket control client.
+#[tauri::command]
+async fn obs_control_start_record(state: State<'_, AppState>) -> Result<(), String> {
+    let guard = state.obs_control.lock().await;
+    let client = guard.as_ref().ok_or("Not connected to OBS WebSocket")?;
+    client.start_record().await.map_err(|e| e.to_string())
+}
+
+/// Stop recording via the raw obs-websocket control client.
+#[tauri::command]
+async fn obs_control_stop_record(state: State<'_, AppState>) -> Result<(), String> {
+    let guard = state.obs_control.lock().await;
+    let client = guard.as_ref().ok_or("Not connected to OBS WebSocket")?;
+    client.stop_record().await.map_err(|e| e.to_string())
+}
+
+/// Toggle recording pause via the raw obs-websocket control client.
+#[tauri::command]
+async fn obs_control_toggle_record_pause(state: State<'_, AppState>) -> Result<(), String> {
+    let guard = state.obs_control.lock().await;
+    let client = guard.as_ref().ok_or("Not connected to OBS WebSocket")?;
+    client.toggle_record_pause().await.map_err(|e| e.to_string())
+}
+
+/// Get recording status via the raw obs-websocket control client.
+#[tauri::command]
+async fn obs_control_get_record_status(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+    let guard = state.obs_control.lock().await;
+    let client = guard.as_ref().ok_or("Not connected to OBS WebSocket")?;
+    client.get_record_status().await.map_err(|e| e.to_string())
+}
+
+/// Save the replay buffer via the raw obs-websocket control client.
+#[tauri::command]
+async fn obs_control_save_replay_buffer(state: State<'_, AppState>) -> Result<(), String> {
+    let guard = state.obs_control.lock().await;
+    let client = guard.as_ref().ok_or("Not connected to OBS WebSocket")?;
+    client.save_replay_buffer().await.map_err(|e| e.to_string())
+}
+
+// ==================== Embeddings Commands ====================
+
+/// Embed a batch of texts with the configured embedding model, for semantic search/clustering
+/// over transcripts.
+#[tauri::command]
+async fn embed_texts(
+    state: State<'_, AppState>,
+    texts: Vec<String>,
+) -> Result<Vec<Vec<f32>>, String> {
+    let config = state.config.lock().await.clone();
+    let model = config
+        .embedding_model
+        .clone()
+        .ok_or("No embedding model configured")?;
+
+    let (base_url, bearer_token) = state.process_manager.lock().await.ollama_connection();
+
+    embeddings::embed(&base_url, bearer_token.as_deref(), &model, config.num_ctx, &texts)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 // ==================== End OBS Commands ====================
 
 fn main() {
     // Load or create configuration
     let config = load_config().unwrap_or_default();
+    let native_recorder =
+        NativeRecorderController::new(config.mic_sensitivity, config.silence_threshold);
 
     tauri::Builder::default()
+        .plugin(supervisor::init())
         .manage(AppState {
             process_manager: Mutex::new(ProcessManager::new()),
             config: Mutex::new(config),
             obs_manager: Mutex::new(OBSManager::new()),
-            native_recorder: NativeRecorderController::new(),
+            obs_control: Mutex::new(None),
+            native_recorder,
         })
         .invoke_handler(tauri::generate_handler![
             is_first_run,
             complete_setup,
+            set_auto_launch,
             get_config,
             update_config,
             start_services,
             stop_services,
             get_service_status,
             download_model,
+            list_ollama_models,
+            check_model_updates,
             check_backend_health,
             check_bundled_models,
             save_recorded_audio,
             native_start_recording,
             native_stop_recording,
+            native_list_input_devices,
+            native_reprocess_recording,
             native_pause_recording,
             native_resume_recording,
             native_is_recording,
+            native_set_sensitivity,
+            native_subscribe_audio_level,
+            native_subscribe_meter_frames,
             // OBS commands
             obs_detect,
             obs_connect,
+            obs_connect_with_profile,
+            obs_list_connection_profiles,
+            obs_connect_resilient,
+            obs_connection_state,
             obs_disconnect,
             obs_is_connected,
+            obs_subscribe_events,
             obs_get_audio_sources,
             obs_start_recording,
             obs_stop_recording,
@@ -440,28 +1044,91 @@ fn main() {
             obs_get_filter_presets,
             obs_set_source_volume,
             obs_set_source_muted,
+            obs_list_scenes,
+            obs_current_scene,
+            obs_set_scene,
+            obs_list_scene_collections,
+            obs_set_scene_collection,
+            obs_list_profiles,
+            obs_set_profile,
+            obs_list_transitions,
+            obs_set_transition,
+            obs_apply_recording_profile,
+            obs_start_streaming,
+            obs_stop_streaming,
+            obs_streaming_status,
+            obs_start_replay_buffer,
+            obs_stop_replay_buffer,
+            obs_replay_buffer_status,
+            obs_save_replay,
+            obs_split_recording,
             // OBS installation commands
             obs_download_and_install,
             obs_configure,
+            obs_get_generated_password,
+            obs_enumerate_asio_devices,
             obs_launch,
             obs_get_download_url,
+            // OBS WebSocket control commands
+            obs_control_connect,
+            obs_control_connect_resilient,
+            obs_control_get_version,
+            obs_control_apply_lecture_hall_filters,
+            obs_control_start_record,
+            obs_control_stop_record,
+            obs_control_toggle_record_pause,
+            obs_control_get_record_status,
+            obs_control_save_replay_buffer,
+            // Embeddings commands
+            embed_texts,
+            // Self-update commands
+            check_for_update,
+            download_update,
+            install_update,
         ])
         .setup(|app| {
             // Perform any initial setup here
             println!("CogniScribe starting...");
+
+            // Check for an app update in the background, gated behind the user's preference so
+            // this doesn't surprise anyone who'd rather stay on a pinned build.
+            let app_handle = app.handle();
+            tauri::async_runtime::spawn(async move {
+                let state = app_handle.state::<AppState>();
+                let auto_updates = state.config.lock().await.auto_updates;
+                if !auto_updates {
+                    return;
+                }
+
+                let current_version = app_handle.package_info().version.to_string();
+                match updater::check_for_update(&current_version).await {
+                    Ok(Some(update)) => {
+                        let _ = app_handle.emit_all("update-available", update);
+                    }
+                    Ok(None) => {}
+                    Err(e) => println!("Update check failed: {}", e),
+                }
+            });
+
             Ok(())
         })
         .on_window_event(|event| {
             if let tauri::WindowEvent::CloseRequested { .. } = event.event() {
-                // Graceful shutdown handled by Tauri's lifecycle
-                println!("Window closing, services will be cleaned up");
+                // Stop the supervisor before the window (and its services) go away, so it
+                // doesn't observe the services stopping and "helpfully" restart them.
+                println!("Window closing, stopping service supervisor");
+                let supervisor = event.window().state::<Mutex<ServiceSupervisor>>();
+                tauri::async_runtime::block_on(async {
+                    supervisor.lock().await.stop();
+                });
             }
         })
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run(|_app_handle, event| {
             if let tauri::RunEvent::ExitRequested { .. } = event {
-                // Cleanup happens here
+                // The service-supervisor plugin's own `on_event` hook stops the supervisor for
+                // this event; nothing else to clean up here.
                 println!("Application exiting");
             }
         });
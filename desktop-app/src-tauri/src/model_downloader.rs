@@ -1,11 +1,24 @@
 use anyhow::{Context, Result};
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 use sha2::{Digest, Sha256};
 
+const OLLAMA_GITHUB_RELEASES_API: &str = "https://api.github.com/repos/ollama/ollama/releases/latest";
+
+/// Stage of a model/binary download, mirroring `OBSInstallProgress`'s `stage` field but as a
+/// typed enum so the setup UI can switch on it instead of matching strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DownloadPhase {
+    Resolving,
+    Downloading,
+    Verifying,
+    Extracting,
+}
+
 fn checksum_required() -> bool {
     matches!(
         std::env::var("OLLAMA_REQUIRE_CHECKSUM")
@@ -34,6 +47,17 @@ fn hex_encode(bytes: &[u8]) -> String {
     out
 }
 
+/// SHA-256 of a file already on disk, for validating an installed Ollama binary against a
+/// freshly-fetched checksum without re-downloading it.
+async fn hash_file(path: &Path) -> Result<String> {
+    let bytes = fs::read(path)
+        .await
+        .context("Failed to read Ollama binary for checksum verification")?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(hex_encode(&hasher.finalize()))
+}
+
 async fn fetch_checksum(url: &str) -> Result<Option<String>> {
     let client = reqwest::Client::new();
     let suffixes = [".sha256", ".sha256sum"];
@@ -69,6 +93,7 @@ async fn fetch_checksum(url: &str) -> Result<Option<String>> {
 pub struct DownloadProgress {
     pub model_name: String,
     pub status: String, // "downloading" | "complete" | "error"
+    pub phase: DownloadPhase,
     pub percent: f32,
     pub downloaded_bytes: u64,
     pub total_bytes: u64,
@@ -108,7 +133,15 @@ fn ollama_app_path() -> Result<PathBuf> {
     Ok(ollama_app_dir()?.join(ollama_binary_name()))
 }
 
-fn ollama_download_url() -> Result<String> {
+/// Temporary path an in-progress Ollama download is streamed into, so a crash or interrupted
+/// connection never leaves a truncated file sitting at `app_path` looking like a valid binary.
+fn ollama_partial_path(app_path: &Path) -> PathBuf {
+    let mut partial = app_path.as_os_str().to_owned();
+    partial.push(".partial");
+    PathBuf::from(partial)
+}
+
+fn ollama_asset_name() -> Result<String> {
     let os = std::env::consts::OS;
     let arch = match std::env::consts::ARCH {
         "x86_64" => "amd64",
@@ -118,23 +151,103 @@ fn ollama_download_url() -> Result<String> {
         }
     };
 
-    let url = match os {
-        "macos" => "https://github.com/ollama/ollama/releases/latest/download/ollama-darwin"
-            .to_string(),
-        "linux" => format!(
-            "https://github.com/ollama/ollama/releases/latest/download/ollama-linux-{}",
-            arch
-        ),
-        "windows" => format!(
-            "https://github.com/ollama/ollama/releases/latest/download/ollama-windows-{}.exe",
-            arch
-        ),
+    let name = match os {
+        "macos" => "ollama-darwin".to_string(),
+        "linux" => format!("ollama-linux-{}", arch),
+        "windows" => format!("ollama-windows-{}.exe", arch),
         other => {
             anyhow::bail!("Unsupported platform: {}", other);
         }
     };
 
-    Ok(url)
+    Ok(name)
+}
+
+/// Pinned "latest" download URL for the current platform, used when the GitHub releases API
+/// can't be reached (rate-limited, offline, etc). Doesn't carry a version number or checksum.
+fn ollama_download_url() -> Result<String> {
+    Ok(format!(
+        "https://github.com/ollama/ollama/releases/latest/download/{}",
+        ollama_asset_name()?
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// The Ollama release the installer should download: its version tag and the asset URL for the
+/// current platform, resolved from the GitHub releases API.
+#[derive(Debug, Clone)]
+pub struct ResolvedOllamaRelease {
+    pub version: String,
+    pub download_url: String,
+}
+
+/// Resolve the newest Ollama release from GitHub and pick the asset for the current platform.
+async fn resolve_latest_ollama_release() -> Result<ResolvedOllamaRelease> {
+    let client = reqwest::Client::builder()
+        .user_agent("cogniscribe-model-downloader")
+        .build()?;
+
+    let release: GitHubRelease = client
+        .get(OLLAMA_GITHUB_RELEASES_API)
+        .send()
+        .await
+        .context("Failed to query Ollama GitHub releases")?
+        .error_for_status()
+        .context("Ollama GitHub releases API returned an error")?
+        .json()
+        .await
+        .context("Failed to parse Ollama GitHub releases response")?;
+
+    let asset_name = ollama_asset_name()?;
+    let asset = release
+        .assets
+        .iter()
+        .find(|asset| asset.name == asset_name)
+        .ok_or_else(|| anyhow::anyhow!("No Ollama release asset named '{}' found", asset_name))?;
+
+    Ok(ResolvedOllamaRelease {
+        version: release.tag_name,
+        download_url: asset.browser_download_url.clone(),
+    })
+}
+
+/// One entry of `check_model_updates`'s result: a model/binary's installed vs. latest-available
+/// version, and whether those differ.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelUpdateStatus {
+    pub model: String,
+    pub current: Option<String>,
+    pub latest: String,
+    pub update_available: bool,
+}
+
+/// Compare `installed_versions` (persisted in `AppConfig`) against the latest Ollama runtime
+/// release on GitHub. Only the bundled Ollama binary is versioned this way today; Ollama models
+/// pulled via `/api/pull` are tracked by Ollama's own registry digests instead.
+pub async fn check_model_updates(
+    installed_versions: &HashMap<String, String>,
+) -> Result<Vec<ModelUpdateStatus>> {
+    let release = resolve_latest_ollama_release().await?;
+    let current = installed_versions.get("ollama").cloned();
+    let update_available = current.as_deref() != Some(release.version.as_str());
+
+    Ok(vec![ModelUpdateStatus {
+        model: "ollama".to_string(),
+        current,
+        latest: release.version,
+        update_available,
+    }])
 }
 
 pub fn is_ollama_binary_installed(resource_dir: &Path) -> Result<bool> {
@@ -145,7 +258,13 @@ pub fn is_ollama_binary_installed(resource_dir: &Path) -> Result<bool> {
     Ok(ollama_app_path()?.exists())
 }
 
-pub async fn download_ollama_binary<F>(resource_dir: &Path, progress_callback: F) -> Result<()>
+/// Download the Ollama runtime binary if it isn't already installed, returning the version
+/// string that ended up installed (from GitHub's release tag, `"bundled"` if the runtime ships in
+/// `resource_dir`, or `"unknown"` if the releases API couldn't be reached and we fell back to the
+/// pinned "latest" URL). Callers persist the
+/// returned version into `AppConfig::installed_versions` so `check_model_updates` has something
+/// to compare against next time.
+pub async fn download_ollama_binary<F>(resource_dir: &Path, progress_callback: F) -> Result<String>
 where
     F: Fn(DownloadProgress),
 {
@@ -153,61 +272,123 @@ where
         progress_callback(DownloadProgress {
             model_name: "Ollama Runtime".to_string(),
             status: "complete".to_string(),
+            phase: DownloadPhase::Verifying,
             percent: 100.0,
             downloaded_bytes: 0,
             total_bytes: 0,
             message: "Ollama runtime already bundled".to_string(),
         });
-        return Ok(());
+        return Ok("bundled".to_string());
     }
 
-    let app_path = ollama_app_path()?;
-    if app_path.exists() {
-        progress_callback(DownloadProgress {
-            model_name: "Ollama Runtime".to_string(),
-            status: "complete".to_string(),
-            percent: 100.0,
-            downloaded_bytes: 0,
-            total_bytes: 0,
-            message: "Ollama runtime already installed".to_string(),
-        });
-        return Ok(());
-    }
+    progress_callback(DownloadProgress {
+        model_name: "Ollama Runtime".to_string(),
+        status: "downloading".to_string(),
+        phase: DownloadPhase::Resolving,
+        percent: 0.0,
+        downloaded_bytes: 0,
+        total_bytes: 0,
+        message: "Resolving latest Ollama release...".to_string(),
+    });
+
+    let (url, version) = match resolve_latest_ollama_release().await {
+        Ok(release) => (release.download_url, release.version),
+        Err(e) => {
+            println!("Failed to resolve latest Ollama release ({}); using pinned URL", e);
+            (ollama_download_url()?, "unknown".to_string())
+        }
+    };
 
-    let url = ollama_download_url()?;
+    let app_path = ollama_app_path()?;
     let checksum = fetch_checksum(&url).await?;
     if checksum.is_none() && checksum_required() {
         anyhow::bail!("Ollama checksum missing and verification is required");
     }
+
+    if app_path.exists() {
+        let verified = match &checksum {
+            Some(expected) => hash_file(&app_path).await? == *expected,
+            None => !checksum_required(),
+        };
+
+        if verified {
+            progress_callback(DownloadProgress {
+                model_name: "Ollama Runtime".to_string(),
+                status: "complete".to_string(),
+                phase: DownloadPhase::Verifying,
+                percent: 100.0,
+                downloaded_bytes: 0,
+                total_bytes: 0,
+                message: "Ollama runtime already installed".to_string(),
+            });
+            return Ok(version);
+        }
+
+        println!("Existing Ollama binary failed checksum verification; re-downloading");
+        fs::remove_file(&app_path)
+            .await
+            .context("Failed to remove stale Ollama binary")?;
+    }
+
     let app_dir = ollama_app_dir()?;
     fs::create_dir_all(&app_dir)
         .await
         .context("Failed to create Ollama directory")?;
 
+    let partial_path = ollama_partial_path(&app_path);
+    let mut already_downloaded = fs::metadata(&partial_path)
+        .await
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+
     progress_callback(DownloadProgress {
         model_name: "Ollama Runtime".to_string(),
         status: "downloading".to_string(),
+        phase: DownloadPhase::Downloading,
         percent: 0.0,
-        downloaded_bytes: 0,
+        downloaded_bytes: already_downloaded,
         total_bytes: 0,
-        message: "Downloading Ollama runtime...".to_string(),
+        message: if already_downloaded > 0 {
+            "Resuming Ollama runtime download...".to_string()
+        } else {
+            "Downloading Ollama runtime...".to_string()
+        },
     });
 
     let client = reqwest::Client::new();
-    let response = client
-        .get(url)
+    let mut request = client.get(&url);
+    if already_downloaded > 0 {
+        request = request.header("Range", format!("bytes={}-", already_downloaded));
+    }
+
+    let response = request
         .send()
         .await
         .context("Failed to download Ollama runtime")?
         .error_for_status()
         .context("Ollama runtime download failed")?;
 
-    let total = response.content_length().unwrap_or(0);
-    let mut downloaded: u64 = 0;
-    let mut file = fs::File::create(&app_path)
-        .await
-        .context("Failed to create Ollama binary file")?;
     let mut hasher = Sha256::new();
+    let mut file = if response.status() == reqwest::StatusCode::PARTIAL_CONTENT && already_downloaded > 0 {
+        let existing = fs::read(&partial_path)
+            .await
+            .context("Failed to read partial Ollama download")?;
+        hasher.update(&existing);
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&partial_path)
+            .await
+            .context("Failed to reopen partial Ollama download")?
+    } else {
+        // Server ignored the Range request (or this is a fresh download): start from scratch.
+        already_downloaded = 0;
+        fs::File::create(&partial_path)
+            .await
+            .context("Failed to create Ollama binary file")?
+    };
+
+    let total = response.content_length().map(|len| len + already_downloaded).unwrap_or(0);
+    let mut downloaded = already_downloaded;
 
     let mut stream = response.bytes_stream();
     while let Some(chunk) = stream.next().await {
@@ -227,6 +408,7 @@ where
         progress_callback(DownloadProgress {
             model_name: "Ollama Runtime".to_string(),
             status: "downloading".to_string(),
+            phase: DownloadPhase::Downloading,
             percent,
             downloaded_bytes: downloaded,
             total_bytes: if total > 0 { total } else { downloaded },
@@ -237,15 +419,30 @@ where
     file.flush()
         .await
         .context("Failed to finalize Ollama binary")?;
+    drop(file);
+
+    progress_callback(DownloadProgress {
+        model_name: "Ollama Runtime".to_string(),
+        status: "downloading".to_string(),
+        phase: DownloadPhase::Verifying,
+        percent: 100.0,
+        downloaded_bytes: downloaded,
+        total_bytes: if total > 0 { total } else { downloaded },
+        message: "Verifying Ollama runtime checksum...".to_string(),
+    });
 
     if let Some(expected) = checksum {
         let digest = hex_encode(&hasher.finalize());
         if digest != expected {
-            let _ = std::fs::remove_file(&app_path);
+            let _ = fs::remove_file(&partial_path).await;
             anyhow::bail!("Ollama checksum verification failed");
         }
     }
 
+    fs::rename(&partial_path, &app_path)
+        .await
+        .context("Failed to finalize Ollama binary")?;
+
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
@@ -260,13 +457,14 @@ where
     progress_callback(DownloadProgress {
         model_name: "Ollama Runtime".to_string(),
         status: "complete".to_string(),
+        phase: DownloadPhase::Verifying,
         percent: 100.0,
         downloaded_bytes: downloaded,
         total_bytes: if total > 0 { total } else { downloaded },
         message: "Ollama runtime ready".to_string(),
     });
 
-    Ok(())
+    Ok(version)
 }
 
 /// Download Whisper model
@@ -286,6 +484,7 @@ where
     progress_callback(DownloadProgress {
         model_name: "whisper-base".to_string(),
         status: "downloading".to_string(),
+        phase: DownloadPhase::Downloading,
         percent: 0.0,
         downloaded_bytes: 0,
         total_bytes: 150_000_000, // ~150MB for base model
@@ -296,6 +495,7 @@ where
     progress_callback(DownloadProgress {
         model_name: "whisper-base".to_string(),
         status: "complete".to_string(),
+        phase: DownloadPhase::Extracting,
         percent: 100.0,
         downloaded_bytes: 150_000_000,
         total_bytes: 150_000_000,
@@ -306,39 +506,54 @@ where
     Ok(())
 }
 
-/// Download Ollama model using Ollama API
-pub async fn download_ollama_model<F>(progress_callback: F) -> Result<()>
+/// Download `model_name` using the Ollama pull API. `base_url` and `bearer_token` identify the
+/// Ollama server to pull from, mirroring `ensure_model` so a remote/authenticated server (set via
+/// `AppConfig::ollama_api_url`/`ollama_bearer_token`) works the same as the local bundled daemon.
+/// Progress is driven entirely from the `completed`/`total` fields the pull API streams back, so
+/// it reflects the real size of whatever model was requested instead of a hardcoded estimate.
+pub async fn download_ollama_model<F>(
+    base_url: &str,
+    bearer_token: Option<&str>,
+    model_name: &str,
+    progress_callback: F,
+) -> Result<()>
 where
     F: Fn(DownloadProgress),
 {
-    println!("Downloading Ollama model: llama3.1:8b");
+    println!("Downloading Ollama model: {}", model_name);
 
     let client = reqwest::Client::new();
 
     progress_callback(DownloadProgress {
-        model_name: "llama3.1:8b".to_string(),
+        model_name: model_name.to_string(),
         status: "downloading".to_string(),
+        phase: DownloadPhase::Resolving,
         percent: 0.0,
         downloaded_bytes: 0,
-        total_bytes: 4_700_000_000, // ~4.7GB
+        total_bytes: 0,
         message: "Starting download...".to_string(),
     });
 
     // Use Ollama's pull API
     let request_body = serde_json::json!({
-        "name": "llama3.1:8b",
+        "name": model_name,
         "stream": true
     });
 
-    let mut response = client
-        .post("http://localhost:11436/api/pull")
-        .json(&request_body)
+    let mut request = client
+        .post(format!("{}/api/pull", base_url))
+        .json(&request_body);
+    if let Some(token) = bearer_token {
+        request = request.bearer_auth(token);
+    }
+
+    let mut response = request
         .send()
         .await
         .context("Failed to start Ollama model download")?;
 
     let mut total_downloaded: u64 = 0;
-    let total_size: u64 = 4_700_000_000; // Approximate
+    let mut total_size: u64 = 0;
 
     let mut buffer = String::new();
     let mut stream = response.bytes_stream();
@@ -360,11 +575,13 @@ where
                     }
 
                     if let Some(total) = json.get("total").and_then(|v| v.as_u64()) {
+                        total_size = total;
                         let percent = (total_downloaded as f64 / total as f64 * 100.0) as f32;
 
                         progress_callback(DownloadProgress {
-                            model_name: "llama3.1:8b".to_string(),
+                            model_name: model_name.to_string(),
                             status: "downloading".to_string(),
+                            phase: DownloadPhase::Downloading,
                             percent,
                             downloaded_bytes: total_downloaded,
                             total_bytes: total,
@@ -379,8 +596,9 @@ where
                     if let Some(status) = json.get("status").and_then(|v| v.as_str()) {
                         if status == "success" {
                             progress_callback(DownloadProgress {
-                                model_name: "llama3.1:8b".to_string(),
+                                model_name: model_name.to_string(),
                                 status: "complete".to_string(),
+                                phase: DownloadPhase::Extracting,
                                 percent: 100.0,
                                 downloaded_bytes: total_size,
                                 total_bytes: total_size,
@@ -398,13 +616,206 @@ where
     Ok(())
 }
 
-/// Check if a model is already downloaded
+/// A model installed on (or available from) an Ollama server, as returned by `/api/tags`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelInfo {
+    pub name: String,
+    pub size: u64,
+    pub modified_at: String,
+}
+
+/// List the models installed on the Ollama server at `base_url`. Hitting `/api/tags` is also the
+/// simplest way to probe whether the server is reachable at all, so callers can reuse this as a
+/// liveness check in addition to populating a model picker.
+pub async fn list_installed_models(
+    base_url: &str,
+    bearer_token: Option<&str>,
+) -> Result<Vec<ModelInfo>> {
+    let client = reqwest::Client::new();
+    let mut request = client.get(format!("{}/api/tags", base_url));
+    if let Some(token) = bearer_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response: serde_json::Value = request
+        .send()
+        .await
+        .context("Failed to list Ollama models")?
+        .json()
+        .await
+        .context("Failed to parse Ollama tags response")?;
+
+    let models = response
+        .get("models")
+        .and_then(|v| v.as_array())
+        .map(|models| {
+            models
+                .iter()
+                .filter_map(|m| {
+                    let name = m.get("name").and_then(|n| n.as_str())?.to_string();
+                    let size = m.get("size").and_then(|s| s.as_u64()).unwrap_or(0);
+                    let modified_at = m
+                        .get("modified_at")
+                        .and_then(|d| d.as_str())
+                        .unwrap_or_default()
+                        .to_string();
+                    Some(ModelInfo {
+                        name,
+                        size,
+                        modified_at,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(models)
+}
+
+/// Progress of pulling a model into a running Ollama server via `/api/pull`, mirroring the
+/// `OBSInstallProgress` stage/progress/message shape used for OBS installation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelPullProgress {
+    pub model: String,
+    pub status: String,
+    pub percent: f32,
+    pub completed_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// List the models currently available on the given Ollama server.
+async fn list_ollama_models(base_url: &str, bearer_token: Option<&str>) -> Result<Vec<String>> {
+    let client = reqwest::Client::new();
+    let mut request = client.get(format!("{}/api/tags", base_url));
+    if let Some(token) = bearer_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response: serde_json::Value = request
+        .send()
+        .await
+        .context("Failed to list Ollama models")?
+        .json()
+        .await
+        .context("Failed to parse Ollama tags response")?;
+
+    let models = response
+        .get("models")
+        .and_then(|v| v.as_array())
+        .map(|models| {
+            models
+                .iter()
+                .filter_map(|m| m.get("name").and_then(|n| n.as_str()).map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(models)
+}
+
+/// Ensure `model` is present on the Ollama server at `base_url`, pulling it via `/api/pull` and
+/// reporting incremental progress if it is missing.
+pub async fn ensure_model<F>(
+    base_url: &str,
+    bearer_token: Option<&str>,
+    model: &str,
+    progress_callback: F,
+) -> Result<()>
+where
+    F: Fn(ModelPullProgress),
+{
+    let installed = list_ollama_models(base_url, bearer_token).await?;
+    if installed.iter().any(|name| name == model || name.starts_with(&format!("{}:", model))) {
+        return Ok(());
+    }
+
+    progress_callback(ModelPullProgress {
+        model: model.to_string(),
+        status: "pulling".to_string(),
+        percent: 0.0,
+        completed_bytes: 0,
+        total_bytes: 0,
+    });
+
+    let client = reqwest::Client::new();
+    let mut request = client
+        .post(format!("{}/api/pull", base_url))
+        .json(&serde_json::json!({ "name": model, "stream": true }));
+    if let Some(token) = bearer_token {
+        request = request.bearer_auth(token);
+    }
+
+    let mut response = request
+        .send()
+        .await
+        .context("Failed to start Ollama model pull")?;
+
+    let mut buffer = String::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.context("Model pull interrupted")?;
+        let text = std::str::from_utf8(&chunk).context("Model pull response was not UTF-8")?;
+        buffer.push_str(text);
+
+        while let Some(pos) = buffer.find('\n') {
+            let line = buffer[..pos].trim().to_string();
+            buffer.drain(..=pos);
+            if line.is_empty() {
+                continue;
+            }
+
+            let json: serde_json::Value =
+                serde_json::from_str(&line).context("Failed to parse Ollama pull response line")?;
+
+            if let Some(error) = json.get("error").and_then(|v| v.as_str()) {
+                anyhow::bail!("Ollama model pull failed: {}", error);
+            }
+
+            let status = json
+                .get("status")
+                .and_then(|v| v.as_str())
+                .unwrap_or("pulling")
+                .to_string();
+            let completed = json.get("completed").and_then(|v| v.as_u64()).unwrap_or(0);
+            let total = json.get("total").and_then(|v| v.as_u64()).unwrap_or(0);
+            let percent = if total > 0 {
+                (completed as f64 / total as f64 * 100.0) as f32
+            } else {
+                0.0
+            };
+
+            progress_callback(ModelPullProgress {
+                model: model.to_string(),
+                status: status.clone(),
+                percent,
+                completed_bytes: completed,
+                total_bytes: total,
+            });
+
+            if status == "success" {
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check if a model is already downloaded on the Ollama server at `base_url`.
 #[allow(dead_code)]
-pub async fn is_model_downloaded(model_name: &str) -> Result<bool> {
+pub async fn is_model_downloaded(
+    base_url: &str,
+    bearer_token: Option<&str>,
+    model_name: &str,
+) -> Result<bool> {
     let client = reqwest::Client::new();
 
-    let response = client
-        .get("http://localhost:11436/api/tags")
+    let mut request = client.get(format!("{}/api/tags", base_url));
+    if let Some(token) = bearer_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request
         .send()
         .await
         .context("Failed to check Ollama models")?;
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Maps one ASIO interface input channel to a named OBS audio track, e.g. "input 3" -> "Lapel
+/// Mic", so a multi-channel interface's inputs can be routed individually instead of forcing
+/// everything through the platform default device.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsioChannelRoute {
+    pub input_channel: u32,
+    pub track_name: String,
+}
+
+/// Which kind of audio input source `OBSConfigWriter::create_cogniscribe_scene_*` should emit
+/// into the scene collection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MicrophoneSource {
+    /// The platform default capture device — what the scene collection used exclusively before
+    /// ASIO support existed.
+    Default,
+    /// A multi-channel interface via the obs-asio plugin's `asio_input_capture` source, with
+    /// individual input channels routed to named tracks.
+    Asio {
+        device_name: String,
+        routes: Vec<AsioChannelRoute>,
+    },
+}
+
+impl Default for MicrophoneSource {
+    fn default() -> Self {
+        MicrophoneSource::Default
+    }
+}
+
+/// List the ASIO device names installed on this machine. obs-asio itself discovers devices the
+/// same way any ASIO host app does: each driver registers a subkey under
+/// `HKLM\SOFTWARE\ASIO` in the Windows registry. Returns an empty list (with an explanatory
+/// message) on platforms that don't support ASIO at all.
+pub fn enumerate_asio_devices() -> Result<Vec<String>> {
+    #[cfg(target_os = "windows")]
+    {
+        let output = Command::new("reg")
+            .args(["query", "HKLM\\SOFTWARE\\ASIO"])
+            .output()?;
+
+        let devices = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("HKEY_LOCAL_MACHINE\\SOFTWARE\\ASIO\\"))
+            .map(|name| name.to_string())
+            .collect();
+
+        Ok(devices)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        println!("ASIO device enumeration is only supported on Windows (obs-asio requires the ASIO SDK, which is Windows-only)");
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_microphone_source_is_default_variant() {
+        assert!(matches!(MicrophoneSource::default(), MicrophoneSource::Default));
+    }
+
+    #[test]
+    fn test_asio_channel_route_roundtrips_through_json() {
+        let route = AsioChannelRoute { input_channel: 3, track_name: "Lapel Mic".to_string() };
+        let json = serde_json::to_string(&route).unwrap();
+        let parsed: AsioChannelRoute = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.input_channel, 3);
+        assert_eq!(parsed.track_name, "Lapel Mic");
+    }
+}
@@ -1,7 +1,54 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::path::PathBuf;
 
+/// The password `enable_websocket` generated (or found already set), so other CogniScribe
+/// components can connect without the user having to copy it out of OBS's own settings UI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ObsCredentials {
+    password: String,
+}
+
+fn credentials_file() -> PathBuf {
+    crate::config::get_config_dir().join("obs_credentials.json")
+}
+
+/// Persist the generated password to our own config directory (not OBS's), so
+/// `OBSManager`/`ObsControlClient` can read it back without parsing `global.ini`.
+fn save_credentials(password: &str) -> Result<()> {
+    let config_dir = crate::config::get_config_dir();
+    std::fs::create_dir_all(&config_dir).context("Failed to create config directory")?;
+    std::fs::write(
+        credentials_file(),
+        serde_json::to_string_pretty(&ObsCredentials { password: password.to_string() })?,
+    )
+    .context("Failed to write OBS credentials file")?;
+    Ok(())
+}
+
+/// Read back the password `enable_websocket` previously generated.
+pub fn read_generated_password() -> Result<Option<String>> {
+    let path = credentials_file();
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = std::fs::read_to_string(&path).context("Failed to read OBS credentials file")?;
+    let credentials: ObsCredentials = serde_json::from_str(&contents)
+        .context("Failed to parse OBS credentials file")?;
+    Ok(Some(credentials.password))
+}
+
+fn generate_password() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(24)
+        .map(char::from)
+        .collect()
+}
+
 pub struct OBSConfigWriter;
 
 impl OBSConfigWriter {
@@ -26,8 +73,12 @@ impl OBSConfigWriter {
         }
     }
 
-    /// Enable WebSocket server in OBS global config
-    pub fn enable_websocket() -> Result<()> {
+    /// Enable WebSocket server in OBS global config, requiring authentication with a randomly
+    /// generated password instead of leaving the control port open to anything on localhost
+    /// (important on shared lab machines where multiple users could otherwise hijack each
+    /// other's recordings). Returns the password now in effect, also persisted via
+    /// `save_credentials` so other CogniScribe components can read it back.
+    pub fn enable_websocket() -> Result<String> {
         let config_dir = Self::get_config_dir()?;
         let global_ini = config_dir.join("global.ini");
 
@@ -41,18 +92,26 @@ impl OBSConfigWriter {
             String::new()
         };
 
+        // Reuse the existing password if we've already provisioned one, so reconfiguring
+        // doesn't invalidate credentials other components already have.
+        let password = Self::existing_server_password(&config_content)
+            .or_else(|| read_generated_password().ok().flatten())
+            .filter(|password| !password.is_empty())
+            .unwrap_or_else(generate_password);
+
         // Check if WebSocket section exists
         if !config_content.contains("[OBSWebSocket]") {
             // Add WebSocket section
             config_content.push_str("\n[OBSWebSocket]\n");
             config_content.push_str("ServerEnabled=true\n");
             config_content.push_str("ServerPort=4455\n");
-            config_content.push_str("AuthRequired=false\n");
-            config_content.push_str("ServerPassword=\n");
+            config_content.push_str("AuthRequired=true\n");
+            config_content.push_str(&format!("ServerPassword={}\n", password));
             config_content.push_str("AlertsEnabled=true\n");
         } else {
             // Update existing section
             config_content = config_content.replace("ServerEnabled=false", "ServerEnabled=true");
+            config_content = config_content.replace("AuthRequired=false", "AuthRequired=true");
 
             // Ensure port is set
             if !config_content.contains("ServerPort=") {
@@ -61,17 +120,90 @@ impl OBSConfigWriter {
                     "[OBSWebSocket]\nServerPort=4455",
                 );
             }
+            if !config_content.contains("AuthRequired=") {
+                config_content = config_content.replace(
+                    "[OBSWebSocket]",
+                    "[OBSWebSocket]\nAuthRequired=true",
+                );
+            }
+            config_content = Self::replace_ini_value(&config_content, "ServerPassword", &password);
         }
 
         // Write updated config
         std::fs::write(&global_ini, config_content)?;
+        save_credentials(&password)?;
 
-        println!("WebSocket enabled in OBS config");
-        Ok(())
+        println!("WebSocket enabled in OBS config with authentication required");
+        Ok(password)
+    }
+
+    /// Pull the current `ServerPassword=` value out of an existing `global.ini`, if any.
+    fn existing_server_password(config_content: &str) -> Option<String> {
+        config_content
+            .lines()
+            .find_map(|line| line.strip_prefix("ServerPassword="))
+            .map(|value| value.trim().to_string())
     }
 
-    /// Create a basic scene collection for CogniScribe
+    /// Replace a `key=value` line within `[OBSWebSocket]`, appending it if missing.
+    fn replace_ini_value(config_content: &str, key: &str, value: &str) -> String {
+        let prefix = format!("{}=", key);
+        if config_content.lines().any(|line| line.starts_with(&prefix)) {
+            config_content
+                .lines()
+                .map(|line| {
+                    if line.starts_with(&prefix) {
+                        format!("{}{}", prefix, value)
+                    } else {
+                        line.to_string()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else {
+            config_content.replace(
+                "[OBSWebSocket]",
+                &format!("[OBSWebSocket]\n{}{}", prefix, value),
+            )
+        }
+    }
+
+    /// Create a basic scene collection for CogniScribe with the microphone at unity gain.
     pub fn create_cogniscribe_scene() -> Result<()> {
+        Self::create_cogniscribe_scene_full(
+            1.0,
+            &super::volume_curve::VolumeCurve::default_curve(),
+            &super::asio::MicrophoneSource::Default,
+        )
+    }
+
+    /// Create the scene collection using the volume curve configured for a named profile (e.g.
+    /// `"lecture_hall"`, `"clinical_skills"`) in `volume_curves.json`.
+    pub fn create_cogniscribe_scene_for_profile(profile_name: &str, perceptual_level: f32) -> Result<()> {
+        let curve = super::volume_curve::load_volume_curve_for_profile(profile_name)?;
+        Self::create_cogniscribe_scene_with_volume(perceptual_level, &curve)
+    }
+
+    /// Create a basic scene collection for CogniScribe, mapping `perceptual_level` (a `0.0`..`1.0`
+    /// fader position) through `curve` to the linear gain written into the microphone source's
+    /// `volume` field — so a "Clinical Skills" profile targeting a quieter room and a
+    /// "Lecture Hall" profile targeting a loud one can each set a fader position that actually
+    /// matches perceived loudness, instead of both being stuck at a flat `1.0`.
+    pub fn create_cogniscribe_scene_with_volume(
+        perceptual_level: f32,
+        curve: &super::volume_curve::VolumeCurve,
+    ) -> Result<()> {
+        Self::create_cogniscribe_scene_full(perceptual_level, curve, &super::asio::MicrophoneSource::Default)
+    }
+
+    /// Create the scene collection with full control over the input source: the default
+    /// platform capture device, or a multi-channel ASIO interface via the obs-asio plugin with
+    /// per-channel routing (e.g. lapel mic on input 3, room mic on input 5).
+    pub fn create_cogniscribe_scene_full(
+        perceptual_level: f32,
+        curve: &super::volume_curve::VolumeCurve,
+        mic_source: &super::asio::MicrophoneSource,
+    ) -> Result<()> {
         let config_dir = Self::get_config_dir()?;
         let scenes_dir = config_dir.join("basic/scenes");
 
@@ -79,6 +211,9 @@ impl OBSConfigWriter {
 
         let scene_file = scenes_dir.join("CogniScribe.json");
 
+        let gain = curve.gain_for_level(perceptual_level);
+        let source = Self::microphone_source_json(mic_source, gain);
+
         // Create a simple scene collection with audio input
         let scene_collection = json!({
             "current_scene": "Lecture Recording",
@@ -89,23 +224,7 @@ impl OBSConfigWriter {
                 }
             ],
             "name": "CogniScribe",
-            "sources": [
-                {
-                    "versioned_id": "coreaudio_input_capture",
-                    "name": "Microphone",
-                    "uuid": "default-microphone",
-                    "id": "coreaudio_input_capture",
-                    "settings": {
-                        "device_id": "default"
-                    },
-                    "mixers": 0xFF,
-                    "sync": 0,
-                    "flags": 0,
-                    "volume": 1.0,
-                    "balance": 0.5,
-                    "monitoring_type": 0
-                }
-            ],
+            "sources": [source],
             "current_transition": "Fade",
             "transitions": []
         });
@@ -115,10 +234,53 @@ impl OBSConfigWriter {
             serde_json::to_string_pretty(&scene_collection)?
         )?;
 
-        println!("Created CogniScribe scene collection");
+        println!("Created CogniScribe scene collection (microphone gain: {:.3})", gain);
         Ok(())
     }
 
+    /// Build the `sources[0]` entry for the scene collection: the platform default capture
+    /// device, or an `asio_input_capture` source (the obs-asio plugin's source type) with its
+    /// input-channel-to-track routing written alongside OBS's own settings so the mapping the
+    /// user configured survives a round trip through this file.
+    fn microphone_source_json(mic_source: &super::asio::MicrophoneSource, gain: f32) -> serde_json::Value {
+        use super::asio::MicrophoneSource;
+
+        match mic_source {
+            MicrophoneSource::Default => json!({
+                "versioned_id": "coreaudio_input_capture",
+                "name": "Microphone",
+                "uuid": "default-microphone",
+                "id": "coreaudio_input_capture",
+                "settings": {
+                    "device_id": "default"
+                },
+                "mixers": 0xFF,
+                "sync": 0,
+                "flags": 0,
+                "volume": gain,
+                "balance": 0.5,
+                "monitoring_type": 0
+            }),
+            MicrophoneSource::Asio { device_name, routes } => json!({
+                "versioned_id": "asio_input_capture",
+                "name": "Microphone",
+                "uuid": "asio-microphone",
+                "id": "asio_input_capture",
+                "settings": {
+                    "device_id": device_name,
+                    "route": routes.iter().map(|route| route.input_channel).collect::<Vec<_>>(),
+                    "_cogniscribe_track_names": routes.iter().map(|route| route.track_name.clone()).collect::<Vec<_>>(),
+                },
+                "mixers": 0xFF,
+                "sync": 0,
+                "flags": 0,
+                "volume": gain,
+                "balance": 0.5,
+                "monitoring_type": 0
+            }),
+        }
+    }
+
     /// Set up audio filters for the default microphone
     pub fn setup_audio_filters() -> Result<()> {
         let config_dir = Self::get_config_dir()?;
@@ -198,23 +360,41 @@ impl OBSConfigWriter {
         Ok(())
     }
 
+    /// Default replay buffer length: long enough to catch a missed moment without needing to
+    /// have had recording running continuously for the whole lecture.
+    const DEFAULT_REPLAY_BUFFER_SECONDS: u32 = 300;
+
     /// Configure OBS for optimal recording settings
     pub fn set_recording_settings() -> Result<()> {
+        Self::set_recording_settings_with_replay_buffer(Self::DEFAULT_REPLAY_BUFFER_SECONDS)
+    }
+
+    /// Configure straight-through recording plus the `replaybuf` output OBS supports
+    /// alongside it, so `SaveReplayBuffer` can dump the last `replay_buffer_seconds` to disk on
+    /// demand even if recording wasn't running.
+    pub fn set_recording_settings_with_replay_buffer(replay_buffer_seconds: u32) -> Result<()> {
         let config_dir = Self::get_config_dir()?;
         let basic_ini = config_dir.join("basic/profiles/Untitled/basic.ini");
 
         std::fs::create_dir_all(basic_ini.parent().unwrap())?;
 
+        let encoder = super::encoder::HardwareEncoder::detect();
+        let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+
         let basic_config = format!(
             r#"[Output]
 Mode=Simple
 
 [SimpleOutput]
-FilePath={}/Movies
+FilePath={home}/Movies
 RecFormat=mkv
-RecEncoder=x264
+RecEncoder={encoder}
 RecQuality=Small
 RecAudioBitrate=192
+RecRB=true
+RecRBTime={replay_buffer_seconds}
+RecRBPrefix=Replay
+RecRBSuffix=_cogniscribe
 
 [Audio]
 SampleRate=48000
@@ -228,24 +408,33 @@ OutputCY=1080
 FPSType=0
 FPSCommon=30
 "#,
-            std::env::var("HOME").unwrap_or_else(|_| ".".to_string())
+            home = home,
+            encoder = encoder.rec_encoder_value(),
+            replay_buffer_seconds = replay_buffer_seconds
         );
 
         std::fs::write(&basic_ini, basic_config)?;
 
-        println!("Set optimal recording settings");
+        println!("Set optimal recording settings (encoder: {:?})", encoder);
         Ok(())
     }
 
-    /// Complete OBS configuration setup
-    pub fn configure_all() -> Result<()> {
+    /// Complete OBS configuration setup. Returns the WebSocket password now in effect.
+    ///
+    /// `mic_source` selects the platform default capture device or a routed ASIO interface; pass
+    /// `&MicrophoneSource::Default` for the old default-device-only behavior.
+    pub fn configure_all(mic_source: &super::asio::MicrophoneSource) -> Result<String> {
         println!("Configuring OBS Studio for CogniScribe...");
 
         // Enable WebSocket
-        Self::enable_websocket()?;
+        let password = Self::enable_websocket()?;
 
         // Create scene collection
-        Self::create_cogniscribe_scene()?;
+        Self::create_cogniscribe_scene_full(
+            1.0,
+            &super::volume_curve::VolumeCurve::default_curve(),
+            mic_source,
+        )?;
 
         // Set up filters
         Self::setup_audio_filters()?;
@@ -254,7 +443,7 @@ FPSCommon=30
         Self::set_recording_settings()?;
 
         println!("OBS configuration complete!");
-        Ok(())
+        Ok(password)
     }
 }
 
@@ -291,11 +480,44 @@ mod tests {
     #[test]
     fn test_enable_websocket_content() {
         // Test the content that would be written
-        let websocket_config = "[OBSWebSocket]\nServerEnabled=true\nServerPort=4455\nAuthRequired=false\n";
+        let websocket_config = "[OBSWebSocket]\nServerEnabled=true\nServerPort=4455\nAuthRequired=true\nServerPassword=abc123\n";
 
         assert!(websocket_config.contains("ServerEnabled=true"));
         assert!(websocket_config.contains("ServerPort=4455"));
-        assert!(websocket_config.contains("AuthRequired=false"));
+        assert!(websocket_config.contains("AuthRequired=true"));
+        assert!(websocket_config.contains("ServerPassword=abc123"));
+    }
+
+    #[test]
+    fn test_generate_password_is_reasonably_long_and_varies() {
+        let a = generate_password();
+        let b = generate_password();
+        assert_eq!(a.len(), 24);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_existing_server_password_extracts_value() {
+        let content = "[OBSWebSocket]\nServerEnabled=true\nServerPassword=hunter2\n";
+        assert_eq!(
+            OBSConfigWriter::existing_server_password(content),
+            Some("hunter2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replace_ini_value_updates_in_place() {
+        let content = "[OBSWebSocket]\nServerPassword=old\nAlertsEnabled=true";
+        let updated = OBSConfigWriter::replace_ini_value(content, "ServerPassword", "new");
+        assert!(updated.contains("ServerPassword=new"));
+        assert!(!updated.contains("ServerPassword=old"));
+    }
+
+    #[test]
+    fn test_replace_ini_value_appends_when_missing() {
+        let content = "[OBSWebSocket]\nServerEnabled=true";
+        let updated = OBSConfigWriter::replace_ini_value(content, "ServerPassword", "new");
+        assert!(updated.contains("ServerPassword=new"));
     }
 
     #[test]
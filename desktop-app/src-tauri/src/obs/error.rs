@@ -0,0 +1,126 @@
+use std::fmt;
+
+/// User-facing classification of OBS operation failures, so the UI can show an actionable
+/// message ("enable the WebSocket server") instead of an opaque transport error string.
+#[derive(Debug)]
+pub enum ObsError {
+    NotRunning,
+    WebSocketDisabled,
+    AuthenticationFailed,
+    RecordingPathInvalid(String),
+    AlreadyRecording,
+    ConnectionLost,
+    /// The chosen hardware encoder (NVENC, QSV, AMF, VideoToolbox, ...) couldn't initialize,
+    /// e.g. a missing/outdated GPU driver. Surfaced as a hard error rather than a `println!` so
+    /// a student isn't left believing they have a recording when OBS silently produced none.
+    EncoderInitFailed(String),
+    Protocol(String),
+}
+
+impl fmt::Display for ObsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObsError::NotRunning => write!(
+                f,
+                "OBS Studio doesn't appear to be running. Start OBS and try again."
+            ),
+            ObsError::WebSocketDisabled => write!(
+                f,
+                "OBS's WebSocket server is disabled. Enable it from Tools > WebSocket Server Settings in OBS."
+            ),
+            ObsError::AuthenticationFailed => write!(
+                f,
+                "OBS rejected the WebSocket password. Check Tools > WebSocket Server Settings for the correct password."
+            ),
+            ObsError::RecordingPathInvalid(path) => write!(
+                f,
+                "OBS can't write to its configured recording path ({}). Check its output settings.",
+                path
+            ),
+            ObsError::AlreadyRecording => write!(f, "OBS is already recording."),
+            ObsError::ConnectionLost => write!(f, "Lost connection to OBS's WebSocket server."),
+            ObsError::EncoderInitFailed(message) => write!(
+                f,
+                "OBS's recording encoder failed to start ({}). Check your GPU driver, or switch to the software (x264) encoder in OBS's output settings.",
+                message
+            ),
+            ObsError::Protocol(message) => write!(f, "OBS WebSocket error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ObsError {}
+
+impl ObsError {
+    /// Best-effort classification of an obws/transport error into one of our variants. obws
+    /// doesn't expose OBS's own status codes (bad path, connect failed, invalid stream,
+    /// disconnected, ...) as distinct error variants, so we pattern-match on the error text.
+    pub fn classify(err: &(dyn std::error::Error + Send + Sync)) -> Self {
+        let message = err.to_string().to_lowercase();
+
+        if message.contains("authentication") || message.contains("password") {
+            ObsError::AuthenticationFailed
+        } else if message.contains("connection refused") || message.contains("connect error") {
+            ObsError::NotRunning
+        } else if message.contains("invalid path") || message.contains("no such file") {
+            ObsError::RecordingPathInvalid(err.to_string())
+        } else if message.contains("already active") || message.contains("already recording") {
+            ObsError::AlreadyRecording
+        } else if message.contains("closed") || message.contains("disconnected") || message.contains("reset") {
+            ObsError::ConnectionLost
+        } else if message.contains("encoder") && (message.contains("initiali") || message.contains("failed") || message.contains("unsupported")) {
+            ObsError::EncoderInitFailed(err.to_string())
+        } else {
+            ObsError::Protocol(err.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct FakeError(String);
+
+    impl fmt::Display for FakeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl std::error::Error for FakeError {}
+
+    #[test]
+    fn test_classify_authentication_failure() {
+        let err = FakeError("Authentication failed".to_string());
+        assert!(matches!(ObsError::classify(&err), ObsError::AuthenticationFailed));
+    }
+
+    #[test]
+    fn test_classify_connection_refused() {
+        let err = FakeError("Connect error: connection refused (os error 61)".to_string());
+        assert!(matches!(ObsError::classify(&err), ObsError::NotRunning));
+    }
+
+    #[test]
+    fn test_classify_encoder_init_failure() {
+        let err = FakeError("Failed to initialize encoder 'jim_nvenc'".to_string());
+        assert!(matches!(ObsError::classify(&err), ObsError::EncoderInitFailed(_)));
+    }
+
+    #[test]
+    fn test_classify_unknown_falls_back_to_protocol() {
+        let err = FakeError("Something unexpected happened".to_string());
+        match ObsError::classify(&err) {
+            ObsError::Protocol(message) => assert_eq!(message, "Something unexpected happened"),
+            other => panic!("expected Protocol variant, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_display_messages_are_actionable() {
+        assert!(ObsError::WebSocketDisabled.to_string().contains("WebSocket Server Settings"));
+        assert!(ObsError::NotRunning.to_string().contains("Start OBS"));
+    }
+}
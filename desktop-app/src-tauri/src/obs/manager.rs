@@ -1,60 +1,150 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
 use obws::Client;
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
 use std::time::Duration;
-use tokio::sync::Mutex;
+use tokio::sync::{broadcast, Mutex as AsyncMutex};
+use tokio::task::JoinHandle;
 
+use super::detector::OBSDetector;
+use super::error::ObsError;
 use super::types::*;
 
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Host/port/password needed to re-dial OBS after the heartbeat notices the connection died.
+#[derive(Clone)]
+struct ConnectionParams {
+    host: String,
+    port: u16,
+    password: Option<String>,
+}
+
+/// The volume/mute/filter-preset state the app has asked OBS to apply, kept around so it can
+/// be replayed after a resilient reconnect (OBS doesn't remember what we asked for across a
+/// fresh socket the way it does across `pause`/`resume`).
+#[derive(Clone, Default)]
+struct AppliedState {
+    volumes: HashMap<String, f32>,
+    mutes: HashMap<String, bool>,
+    filter_presets: HashMap<String, AudioFilterPreset>,
+}
+
 /// Manages OBS WebSocket connection and operations
 pub struct OBSManager {
-    client: Option<Client>,
-    connected: bool,
-    recording: bool,
+    client: Arc<AsyncMutex<Option<Client>>>,
+    connected: Arc<AtomicBool>,
+    recording: Arc<AtomicBool>,
+    event_tx: broadcast::Sender<ObsEvent>,
+    /// Shared with `run_heartbeat`, which respins this on every successful reconnect so the
+    /// relay keeps following whichever `Client` is current instead of going stale after the
+    /// first reconnect.
+    event_relay_task: Arc<AsyncMutex<Option<JoinHandle<()>>>>,
+    heartbeat_task: Option<JoinHandle<()>>,
+    /// Set just before we tear down the client ourselves, so the heartbeat can tell a
+    /// deliberate `disconnect()` apart from OBS dropping the socket out from under us.
+    intentional_disconnect: Arc<AtomicBool>,
+    connection_params: Arc<StdMutex<Option<ConnectionParams>>>,
+    connection_state: Arc<StdMutex<ObsConnectionState>>,
+    applied_state: Arc<StdMutex<AppliedState>>,
 }
 
 impl OBSManager {
     pub fn new() -> Self {
+        let (event_tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
         Self {
-            client: None,
-            connected: false,
-            recording: false,
+            client: Arc::new(AsyncMutex::new(None)),
+            connected: Arc::new(AtomicBool::new(false)),
+            recording: Arc::new(AtomicBool::new(false)),
+            event_tx,
+            event_relay_task: Arc::new(AsyncMutex::new(None)),
+            heartbeat_task: None,
+            intentional_disconnect: Arc::new(AtomicBool::new(false)),
+            connection_params: Arc::new(StdMutex::new(None)),
+            connection_state: Arc::new(StdMutex::new(ObsConnectionState::Disconnected)),
+            applied_state: Arc::new(StdMutex::new(AppliedState::default())),
+        }
+    }
+
+    /// Subscribe to the OBS event stream, spawning a background relay task on first use.
+    /// The relay keeps `connected`/`recording` in sync with what OBS reports, so manually
+    /// stopping a recording (or quitting OBS) from outside the app is reflected immediately
+    /// instead of only after the next poll.
+    pub async fn subscribe_events(&mut self) -> Result<broadcast::Receiver<ObsEvent>> {
+        self.ensure_event_relay().await?;
+        Ok(self.event_tx.subscribe())
+    }
+
+    async fn ensure_event_relay(&mut self) -> Result<()> {
+        let mut relay_guard = self.event_relay_task.lock().await;
+        if relay_guard.is_some() {
+            return Ok(());
         }
+
+        let client = {
+            let guard = self.client.lock().await;
+            guard.clone().ok_or_else(|| anyhow!("Not connected to OBS"))?
+        };
+
+        *relay_guard = Some(spawn_event_relay(
+            client,
+            self.event_tx.clone(),
+            self.connected.clone(),
+            self.recording.clone(),
+        ));
+        Ok(())
     }
 
     /// Connect to OBS WebSocket server
     pub async fn connect(&mut self, host: &str, port: u16, password: Option<String>) -> Result<OBSConnectionStatus> {
         println!("Connecting to OBS WebSocket at {}:{}...", host, port);
 
+        // Consult the detector first so we can give a precise NotRunning/WebSocketDisabled
+        // error instead of letting an opaque socket-connect failure stand in for it.
+        if let Ok(info) = OBSDetector::detect() {
+            if !info.is_running {
+                return Err(ObsError::NotRunning.into());
+            }
+            if !info.websocket_enabled {
+                return Err(ObsError::WebSocketDisabled.into());
+            }
+        }
+
         // Build connection URL
         let url = format!("{}:{}", host, port);
 
         // Connect to OBS
-        let client = if let Some(pwd) = password {
-            Client::connect_with_password(&url, &pwd).await
-                .context("Failed to connect to OBS WebSocket with password")?
+        let client = if let Some(pwd) = &password {
+            Client::connect_with_password(&url, pwd)
+                .await
+                .map_err(|err| ObsError::classify(&err))?
         } else {
-            Client::connect(&url, None).await
-                .context("Failed to connect to OBS WebSocket")?
+            Client::connect(&url, None)
+                .await
+                .map_err(|err| ObsError::classify(&err))?
         };
 
         // Get version info
         let version = client.general().version().await?;
 
+        let obs_version = version.obs_version.to_string();
         let status = OBSConnectionStatus {
             connected: true,
-            obs_version: Some(version.obs_version.to_string()),
+            available_features: available_features(&obs_version),
+            obs_version: Some(obs_version),
             websocket_version: Some(version.obs_web_socket_version.to_string()),
-            available_features: vec![
-                "recording".to_string(),
-                "audio_sources".to_string(),
-                "filters".to_string(),
-                "scenes".to_string(),
-            ],
         };
 
-        self.client = Some(client);
-        self.connected = true;
+        *self.client.lock().await = Some(client);
+        self.connected.store(true, Ordering::SeqCst);
+        self.intentional_disconnect.store(false, Ordering::SeqCst);
+        *self.connection_state.lock().unwrap() = ObsConnectionState::Connected;
 
         println!("Successfully connected to OBS {} (WebSocket {})",
                  status.obs_version.as_ref().unwrap(),
@@ -63,25 +153,99 @@ impl OBSManager {
         Ok(status)
     }
 
+    /// Connect using a named connection profile from `obs.toml` instead of passing
+    /// host/port/password programmatically. If the profile has a `default_scene`, it's
+    /// switched to right after connecting.
+    pub async fn connect_with_profile(&mut self, profile_name: &str) -> Result<OBSConnectionStatus> {
+        let profile = super::profiles::find_connection_profile(profile_name)?;
+
+        let status = self.connect(&profile.host, profile.port, profile.password.clone()).await?;
+
+        if let Some(scene_name) = &profile.default_scene {
+            self.set_scene(scene_name, None, None).await?;
+        }
+
+        Ok(status)
+    }
+
+    /// Connect to OBS with automatic recovery: a background heartbeat periodically checks
+    /// liveness and, on failure, retries with exponential backoff (1s, 2s, 4s, ... capped at
+    /// 30s) up to `max_reconnect_attempts` times. After a successful reconnect, any
+    /// volume/mute/filter-preset state the app had set is re-applied, and if a recording was
+    /// active when the connection dropped, an `ObsEvent::RecordingInterrupted` is emitted so
+    /// the caller can decide whether to resume it.
+    pub async fn connect_resilient(
+        &mut self,
+        host: &str,
+        port: u16,
+        password: Option<String>,
+        max_reconnect_attempts: u32,
+    ) -> Result<OBSConnectionStatus> {
+        let status = self.connect(host, port, password.clone()).await?;
+
+        *self.connection_params.lock().unwrap() = Some(ConnectionParams {
+            host: host.to_string(),
+            port,
+            password,
+        });
+
+        if let Some(handle) = self.heartbeat_task.take() {
+            handle.abort();
+        }
+
+        let handle = tokio::spawn(run_heartbeat(
+            self.client.clone(),
+            self.connected.clone(),
+            self.recording.clone(),
+            self.intentional_disconnect.clone(),
+            self.connection_state.clone(),
+            self.connection_params.clone(),
+            self.applied_state.clone(),
+            self.event_tx.clone(),
+            self.event_relay_task.clone(),
+            max_reconnect_attempts.max(1),
+        ));
+        self.heartbeat_task = Some(handle);
+
+        Ok(status)
+    }
+
+    /// Current state of a `connect_resilient()` connection.
+    pub fn connection_state(&self) -> ObsConnectionState {
+        self.connection_state.lock().unwrap().clone()
+    }
+
     /// Disconnect from OBS
     pub async fn disconnect(&mut self) -> Result<()> {
-        if let Some(client) = self.client.take() {
-            drop(client);
+        // Mark this as intentional first so the heartbeat (if running) doesn't race us and
+        // treat the client going away as a failure worth reconnecting from.
+        self.intentional_disconnect.store(true, Ordering::SeqCst);
+
+        if let Some(handle) = self.heartbeat_task.take() {
+            handle.abort();
         }
-        self.connected = false;
-        self.recording = false;
+        if let Some(handle) = self.event_relay_task.lock().await.take() {
+            handle.abort();
+        }
+
+        *self.client.lock().await = None;
+        self.connected.store(false, Ordering::SeqCst);
+        self.recording.store(false, Ordering::SeqCst);
+        *self.connection_state.lock().unwrap() = ObsConnectionState::Disconnected;
+        *self.connection_params.lock().unwrap() = None;
         println!("Disconnected from OBS");
         Ok(())
     }
 
     /// Check if connected to OBS
     pub fn is_connected(&self) -> bool {
-        self.connected
+        self.connected.load(Ordering::SeqCst)
     }
 
     /// Get list of audio input sources
     pub async fn get_audio_sources(&self) -> Result<Vec<OBSAudioSource>> {
-        let client = self.client.as_ref()
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
             .ok_or_else(|| anyhow!("Not connected to OBS"))?;
 
         let inputs = client.inputs().list(None).await?;
@@ -121,11 +285,12 @@ impl OBSManager {
 
     /// Start recording in OBS
     pub async fn start_recording(&mut self) -> Result<()> {
-        let client = self.client.as_ref()
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
             .ok_or_else(|| anyhow!("Not connected to OBS"))?;
 
-        client.recording().start().await?;
-        self.recording = true;
+        client.recording().start().await.map_err(|err| ObsError::classify(&err))?;
+        self.recording.store(true, Ordering::SeqCst);
 
         println!("OBS recording started");
         Ok(())
@@ -133,11 +298,12 @@ impl OBSManager {
 
     /// Stop recording in OBS and return the output file path
     pub async fn stop_recording(&mut self) -> Result<PathBuf> {
-        let client = self.client.as_ref()
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
             .ok_or_else(|| anyhow!("Not connected to OBS"))?;
 
-        let output_path = client.recording().stop().await?;
-        self.recording = false;
+        let output_path = client.recording().stop().await.map_err(|err| ObsError::classify(&err))?;
+        self.recording.store(false, Ordering::SeqCst);
 
         println!("OBS recording stopped: {:?}", output_path);
         Ok(PathBuf::from(output_path.output_path))
@@ -145,7 +311,8 @@ impl OBSManager {
 
     /// Pause recording
     pub async fn pause_recording(&mut self) -> Result<()> {
-        let client = self.client.as_ref()
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
             .ok_or_else(|| anyhow!("Not connected to OBS"))?;
 
         client.recording().pause().await?;
@@ -155,7 +322,8 @@ impl OBSManager {
 
     /// Resume recording
     pub async fn resume_recording(&mut self) -> Result<()> {
-        let client = self.client.as_ref()
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
             .ok_or_else(|| anyhow!("Not connected to OBS"))?;
 
         client.recording().resume().await?;
@@ -165,7 +333,8 @@ impl OBSManager {
 
     /// Get current recording status
     pub async fn get_recording_status(&self) -> Result<OBSRecordingStatus> {
-        let client = self.client.as_ref()
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
             .ok_or_else(|| anyhow!("Not connected to OBS"))?;
 
         let status = client.recording().status().await?;
@@ -181,7 +350,8 @@ impl OBSManager {
 
     /// Apply a filter preset to an audio source
     pub async fn apply_filter_preset(&self, source_name: &str, preset: &AudioFilterPreset) -> Result<()> {
-        let client = self.client.as_ref()
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
             .ok_or_else(|| anyhow!("Not connected to OBS"))?;
 
         println!("Applying '{}' preset to '{}'", preset.name, source_name);
@@ -205,34 +375,393 @@ impl OBSManager {
             client.filters().set_enabled(source_name, &filter_name, filter_config.enabled).await?;
         }
 
+        self.applied_state
+            .lock()
+            .unwrap()
+            .filter_presets
+            .insert(source_name.to_string(), preset.clone());
+
         println!("Successfully applied '{}' preset", preset.name);
         Ok(())
     }
 
-    /// Get available filter presets
+    /// Get available filter presets: the three built-in ones plus any institution-defined
+    /// `[[filter_preset]]` entries from `obs.toml`.
     pub fn get_filter_presets() -> Vec<AudioFilterPreset> {
-        vec![
+        let mut presets = vec![
             AudioFilterPreset::lecture_hall(),
             AudioFilterPreset::clinical_skills(),
             AudioFilterPreset::online_lecture(),
-        ]
+        ];
+
+        match super::profiles::load_custom_filter_presets() {
+            Ok(custom) => presets.extend(custom),
+            Err(err) => println!("Failed to load custom filter presets from obs.toml: {}", err),
+        }
+
+        presets
     }
 
     /// Set audio source volume
     pub async fn set_source_volume(&self, source_name: &str, volume_db: f32) -> Result<()> {
-        let client = self.client.as_ref()
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
             .ok_or_else(|| anyhow!("Not connected to OBS"))?;
 
         client.inputs().set_volume(source_name, obws::requests::inputs::Volume::Db(volume_db)).await?;
+        self.applied_state
+            .lock()
+            .unwrap()
+            .volumes
+            .insert(source_name.to_string(), volume_db);
         Ok(())
     }
 
     /// Mute/unmute audio source
     pub async fn set_source_muted(&self, source_name: &str, muted: bool) -> Result<()> {
-        let client = self.client.as_ref()
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
             .ok_or_else(|| anyhow!("Not connected to OBS"))?;
 
         client.inputs().set_muted(source_name, muted).await?;
+        self.applied_state
+            .lock()
+            .unwrap()
+            .mutes
+            .insert(source_name.to_string(), muted);
+        Ok(())
+    }
+
+    /// List all scenes in the current OBS scene collection
+    pub async fn list_scenes(&self) -> Result<Vec<SceneInfo>> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let scenes = client.scenes().list().await?;
+        Ok(scenes
+            .scenes
+            .into_iter()
+            .map(|scene| SceneInfo {
+                name: scene.id.name,
+                index: scene.index as u32,
+            })
+            .collect())
+    }
+
+    /// Get the name of the current program scene
+    pub async fn current_scene(&self) -> Result<String> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let scenes = client.scenes().list().await?;
+        Ok(scenes.current_program_scene.map(|s| s.name).unwrap_or_default())
+    }
+
+    /// Switch the current program scene, optionally selecting a transition (and its duration)
+    /// to use for the switch.
+    pub async fn set_scene(
+        &self,
+        scene_name: &str,
+        transition_name: Option<&str>,
+        transition_duration_ms: Option<u32>,
+    ) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        if let Some(duration_ms) = transition_duration_ms {
+            client
+                .transitions()
+                .set_current_duration(Duration::from_millis(duration_ms as u64))
+                .await
+                .map_err(|err| ObsError::classify(&err))?;
+        }
+        if let Some(name) = transition_name {
+            client
+                .transitions()
+                .set_current(name)
+                .await
+                .map_err(|err| ObsError::classify(&err))?;
+        }
+
+        client
+            .scenes()
+            .set_current_program_scene(scene_name)
+            .await
+            .map_err(|err| ObsError::classify(&err))?;
+
+        println!("Switched to scene '{}'", scene_name);
+        Ok(())
+    }
+
+    /// List all scene collections known to OBS, with `current` marking the active one
+    pub async fn list_scene_collections(&self) -> Result<Vec<OBSSceneCollection>> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let collections = client.scene_collections().list().await?;
+        Ok(collections
+            .collections
+            .into_iter()
+            .map(|name| OBSSceneCollection {
+                current: name == collections.current,
+                name,
+            })
+            .collect())
+    }
+
+    /// Switch OBS to a different scene collection. This reloads OBS's entire scene/source set,
+    /// which takes it a moment, so we poll `list_scene_collections` afterwards until the switch
+    /// is reflected before returning control to the caller.
+    pub async fn set_scene_collection(&self, name: &str) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let collections = client.scene_collections().list().await?;
+        if !collections.collections.iter().any(|c| c == name) {
+            return Err(anyhow!("Scene collection '{}' does not exist in OBS", name));
+        }
+
+        client
+            .scene_collections()
+            .set_current(name)
+            .await
+            .map_err(|err| ObsError::classify(&err))?;
+
+        drop(guard);
+        self.wait_for_reload(name, || async {
+            let guard = self.client.lock().await;
+            let client = guard.as_ref().ok_or_else(|| anyhow!("Not connected to OBS"))?;
+            Ok(client.scene_collections().list().await?.current)
+        })
+        .await?;
+
+        println!("Switched to scene collection '{}'", name);
+        Ok(())
+    }
+
+    /// List all OBS profiles, with `current` marking the active one
+    pub async fn list_profiles(&self) -> Result<Vec<OBSProfile>> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let profiles = client.profiles().list().await?;
+        Ok(profiles
+            .profiles
+            .into_iter()
+            .map(|name| OBSProfile {
+                current: name == profiles.current,
+                name,
+            })
+            .collect())
+    }
+
+    /// Switch OBS to a different profile (output/encoder/device settings). Like scene
+    /// collections, this takes OBS a moment to reload, so we poll until it takes effect.
+    pub async fn set_profile(&self, name: &str) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let profiles = client.profiles().list().await?;
+        if !profiles.profiles.iter().any(|p| p == name) {
+            return Err(anyhow!("Profile '{}' does not exist in OBS", name));
+        }
+
+        client
+            .profiles()
+            .set_current(name)
+            .await
+            .map_err(|err| ObsError::classify(&err))?;
+
+        drop(guard);
+        self.wait_for_reload(name, || async {
+            let guard = self.client.lock().await;
+            let client = guard.as_ref().ok_or_else(|| anyhow!("Not connected to OBS"))?;
+            Ok(client.profiles().list().await?.current)
+        })
+        .await?;
+
+        println!("Switched to profile '{}'", name);
+        Ok(())
+    }
+
+    /// List all scene transitions available in the current scene collection
+    pub async fn list_transitions(&self) -> Result<Vec<OBSTransition>> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let transitions = client.transitions().list().await?;
+        let current_name = transitions.current.as_ref().map(|t| t.name.clone());
+        Ok(transitions
+            .transitions
+            .into_iter()
+            .map(|transition| OBSTransition {
+                current: Some(&transition.name) == current_name.as_ref(),
+                name: transition.name,
+            })
+            .collect())
+    }
+
+    /// Select the scene transition used for subsequent scene switches
+    pub async fn set_transition(&self, name: &str) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let transitions = client.transitions().list().await?;
+        if !transitions.transitions.iter().any(|t| t.name == name) {
+            return Err(anyhow!("Transition '{}' does not exist in OBS", name));
+        }
+
+        client
+            .transitions()
+            .set_current(name)
+            .await
+            .map_err(|err| ObsError::classify(&err))?;
+
+        println!("Switched to transition '{}'", name);
+        Ok(())
+    }
+
+    /// Poll `current_name` every 200ms, up to 5s, until it reports `expected`. Scene collection
+    /// and profile switches reload state on OBS's side asynchronously, so callers that change
+    /// to `expected` need to wait here before issuing further requests against the new state.
+    async fn wait_for_reload<F, Fut>(&self, expected: &str, mut current_name: F) -> Result<()>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<String>>,
+    {
+        const POLL_INTERVAL: Duration = Duration::from_millis(200);
+        const MAX_ATTEMPTS: u32 = 25;
+
+        for _ in 0..MAX_ATTEMPTS {
+            if current_name().await? == expected {
+                return Ok(());
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+
+        Err(anyhow!("Timed out waiting for OBS to finish reloading"))
+    }
+
+    /// Switch to a recording profile's scene and reapply its audio setup (filter preset,
+    /// volume) in one step, so a structured multi-segment capture can drive both at once
+    /// instead of the caller sequencing `set_scene` + `apply_filter_preset` itself.
+    pub async fn apply_recording_profile(&self, profile: &RecordingProfile) -> Result<()> {
+        self.set_scene(
+            &profile.scene_name,
+            profile.transition_name.as_deref(),
+            profile.transition_duration_ms,
+        )
+        .await?;
+
+        self.apply_filter_preset(&profile.audio_source_name, &profile.filter_preset)
+            .await?;
+
+        if let Some(volume_db) = profile.volume_db {
+            self.set_source_volume(&profile.audio_source_name, volume_db).await?;
+        }
+
+        println!("Applied recording profile '{}'", profile.name);
+        Ok(())
+    }
+
+    /// Start streaming to the destination configured in OBS
+    pub async fn start_streaming(&self) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        client.streaming().start().await.map_err(|err| ObsError::classify(&err))?;
+        println!("OBS streaming started");
+        Ok(())
+    }
+
+    /// Stop streaming
+    pub async fn stop_streaming(&self) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        client.streaming().stop().await.map_err(|err| ObsError::classify(&err))?;
+        println!("OBS streaming stopped");
+        Ok(())
+    }
+
+    /// Get current streaming status
+    pub async fn streaming_status(&self) -> Result<OBSStreamingStatus> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let status = client.streaming().status().await?;
+        Ok(OBSStreamingStatus {
+            streaming: status.active,
+            duration_seconds: status.duration.as_secs(),
+            bytes: status.bytes,
+        })
+    }
+
+    /// Start the replay buffer, so `save_replay()` has something to flush to disk later
+    pub async fn start_replay_buffer(&self) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        client.replay_buffer().start().await.map_err(|err| ObsError::classify(&err))?;
+        println!("OBS replay buffer started");
+        Ok(())
+    }
+
+    /// Stop the replay buffer
+    pub async fn stop_replay_buffer(&self) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        client.replay_buffer().stop().await.map_err(|err| ObsError::classify(&err))?;
+        println!("OBS replay buffer stopped");
+        Ok(())
+    }
+
+    /// Get whether the replay buffer is currently active
+    pub async fn replay_buffer_status(&self) -> Result<OBSReplayBufferStatus> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        let status = client.replay_buffer().status().await?;
+        Ok(OBSReplayBufferStatus { active: status })
+    }
+
+    /// Save the last N seconds of the replay buffer to disk — useful for grabbing a
+    /// just-missed clinical demonstration moment without having to have been recording it.
+    pub async fn save_replay(&self) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        client.replay_buffer().save().await.map_err(|err| ObsError::classify(&err))?;
+        println!("Saved replay buffer clip");
+        Ok(())
+    }
+
+    /// Split the current recording into a new file without stopping capture (OBS 28+). Lets a
+    /// long lecture be chaptered into separate files, e.g. one per topic segment.
+    pub async fn split_recording(&self) -> Result<()> {
+        let guard = self.client.lock().await;
+        let client = guard.as_ref()
+            .ok_or_else(|| anyhow!("Not connected to OBS"))?;
+
+        client.recording().split().await.map_err(|err| ObsError::classify(&err))?;
+        println!("Split recording into a new file");
         Ok(())
     }
 }
@@ -242,3 +771,229 @@ impl Default for OBSManager {
         Self::new()
     }
 }
+
+/// The feature set OBS's WebSocket API exposes is stable across versions except for
+/// file-splitting, which OBS only added in 28.0. Parse just the major version out of the
+/// `obs_version` string reported by `general().version()` to gate on it; anything we fail to
+/// parse is treated as modern enough, since every OBS still receiving updates is 28+.
+fn available_features(obs_version: &str) -> Vec<String> {
+    let mut features = vec![
+        "recording".to_string(),
+        "audio_sources".to_string(),
+        "filters".to_string(),
+        "scenes".to_string(),
+        "streaming".to_string(),
+        "replay_buffer".to_string(),
+    ];
+
+    let major_version = obs_version
+        .split('.')
+        .next()
+        .and_then(|major| major.parse::<u32>().ok());
+
+    if major_version.map(|major| major >= 28).unwrap_or(true) {
+        features.push("split_recording".to_string());
+    }
+
+    features
+}
+
+/// Spawn the background task that relays `client.events()` onto `event_tx`, used both by
+/// `ensure_event_relay()` on first subscribe and by `run_heartbeat()` to respin the relay
+/// against a freshly reconnected `Client`.
+fn spawn_event_relay(
+    client: Client,
+    relay_tx: broadcast::Sender<ObsEvent>,
+    connected: Arc<AtomicBool>,
+    recording: Arc<AtomicBool>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let events = client.events();
+        tokio::pin!(events);
+        while let Some(event) = events.next().await {
+            if let Some(mapped) = map_obws_event(event, &recording) {
+                let _ = relay_tx.send(mapped);
+            }
+        }
+
+        // The stream ended: OBS closed the socket from its side (e.g. the user quit OBS) rather
+        // than us calling `disconnect()`. The heartbeat (if one is running) is responsible for
+        // reconnection; we just reflect that we're no longer live and let subscribers know.
+        connected.store(false, Ordering::SeqCst);
+        let _ = relay_tx.send(ObsEvent::Disconnected);
+    })
+}
+
+/// Map an obws event into our crate-local `ObsEvent`, updating `recording` in lockstep so the
+/// heartbeat's interrupted-recording check stays correct even when recording was stopped from
+/// inside OBS itself. Returns `None` for event types we don't relay to the UI.
+fn map_obws_event(event: obws::events::Event, recording: &Arc<AtomicBool>) -> Option<ObsEvent> {
+    use obws::events::Event;
+
+    match event {
+        Event::RecordStateChanged { active, paused, .. } => {
+            recording.store(active, Ordering::SeqCst);
+            if !active {
+                Some(ObsEvent::RecordingStopped { output_path: None })
+            } else if paused {
+                Some(ObsEvent::RecordingPaused)
+            } else {
+                Some(ObsEvent::RecordingStarted)
+            }
+        }
+        Event::InputMuteStateChanged { input_name, input_muted } => {
+            Some(ObsEvent::InputMuteStateChanged {
+                input_name,
+                muted: input_muted,
+            })
+        }
+        Event::CurrentProgramSceneChanged { scene_name, .. } => {
+            Some(ObsEvent::CurrentSceneChanged { scene_name })
+        }
+        _ => None,
+    }
+}
+
+/// Background heartbeat for `connect_resilient()`. Polls `general().version()` on an
+/// interval; on failure (and only if the disconnect wasn't ours), drops the dead client and
+/// retries with exponential backoff until either a reconnect succeeds or `max_attempts` is
+/// exhausted.
+#[allow(clippy::too_many_arguments)]
+async fn run_heartbeat(
+    client: Arc<AsyncMutex<Option<Client>>>,
+    connected: Arc<AtomicBool>,
+    recording: Arc<AtomicBool>,
+    intentional_disconnect: Arc<AtomicBool>,
+    connection_state: Arc<StdMutex<ObsConnectionState>>,
+    connection_params: Arc<StdMutex<Option<ConnectionParams>>>,
+    applied_state: Arc<StdMutex<AppliedState>>,
+    event_tx: broadcast::Sender<ObsEvent>,
+    event_relay_task: Arc<AsyncMutex<Option<JoinHandle<()>>>>,
+    max_attempts: u32,
+) {
+    loop {
+        tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+
+        if intentional_disconnect.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let healthy = match client.lock().await.as_ref() {
+            Some(c) => c.general().version().await.is_ok(),
+            None => false,
+        };
+
+        if healthy {
+            continue;
+        }
+
+        if intentional_disconnect.load(Ordering::SeqCst) {
+            return;
+        }
+
+        connected.store(false, Ordering::SeqCst);
+        let was_recording = recording.swap(false, Ordering::SeqCst);
+        if was_recording {
+            let _ = event_tx.send(ObsEvent::RecordingInterrupted);
+        }
+        *client.lock().await = None;
+
+        let params = match connection_params.lock().unwrap().clone() {
+            Some(p) => p,
+            None => return,
+        };
+
+        let mut attempt = 0u32;
+        let mut delay = RECONNECT_BASE_DELAY;
+
+        loop {
+            attempt += 1;
+            *connection_state.lock().unwrap() = ObsConnectionState::Reconnecting { attempt };
+            let _ = event_tx.send(ObsEvent::Reconnecting { attempt });
+
+            if intentional_disconnect.load(Ordering::SeqCst) {
+                return;
+            }
+
+            let url = format!("{}:{}", params.host, params.port);
+            let reconnected = if let Some(pwd) = &params.password {
+                Client::connect_with_password(&url, pwd).await
+            } else {
+                Client::connect(&url, None).await
+            };
+
+            match reconnected {
+                Ok(new_client) => {
+                    reapply_state(&new_client, &applied_state).await;
+
+                    // Respin the event relay against the new client: the old relay task already
+                    // exited when the previous client's socket died, so without this the event
+                    // stream stays dead for the rest of the connection's life.
+                    let mut relay_guard = event_relay_task.lock().await;
+                    if let Some(old_handle) = relay_guard.take() {
+                        old_handle.abort();
+                    }
+                    *relay_guard = Some(spawn_event_relay(
+                        new_client.clone(),
+                        event_tx.clone(),
+                        connected.clone(),
+                        recording.clone(),
+                    ));
+                    drop(relay_guard);
+
+                    *client.lock().await = Some(new_client);
+                    connected.store(true, Ordering::SeqCst);
+                    *connection_state.lock().unwrap() = ObsConnectionState::Connected;
+                    let _ = event_tx.send(ObsEvent::Reconnected);
+                    break;
+                }
+                Err(_) if attempt >= max_attempts => {
+                    *connection_state.lock().unwrap() = ObsConnectionState::Disconnected;
+                    let _ = event_tx.send(ObsEvent::Disconnected);
+                    return;
+                }
+                Err(_) => {
+                    tokio::time::sleep(delay).await;
+                    delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+                }
+            }
+        }
+    }
+}
+
+/// Re-apply volume/mute/filter-preset state the app had set before a resilient reconnect.
+/// Best-effort: a source that no longer exists just fails its individual call silently,
+/// the same way the original `apply_filter_preset` ignores "filter already exists" errors.
+async fn reapply_state(client: &Client, applied_state: &Arc<StdMutex<AppliedState>>) {
+    let state = applied_state.lock().unwrap().clone();
+
+    for (source_name, volume_db) in &state.volumes {
+        let _ = client
+            .inputs()
+            .set_volume(source_name, obws::requests::inputs::Volume::Db(*volume_db))
+            .await;
+    }
+
+    for (source_name, muted) in &state.mutes {
+        let _ = client.inputs().set_muted(source_name, *muted).await;
+    }
+
+    for (source_name, preset) in &state.filter_presets {
+        for (index, filter_config) in preset.filters.iter().enumerate() {
+            let filter_name = format!("{}_{}", filter_config.filter_type, index);
+            let _ = client
+                .filters()
+                .create(obws::requests::filters::Create {
+                    source_name,
+                    filter_name: &filter_name,
+                    filter_kind: &filter_config.filter_type,
+                    filter_settings: Some(filter_config.settings.clone()),
+                })
+                .await;
+            let _ = client
+                .filters()
+                .set_enabled(source_name, &filter_name, filter_config.enabled)
+                .await;
+        }
+    }
+}
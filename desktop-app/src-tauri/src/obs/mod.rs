@@ -1,14 +1,26 @@
 // OBS Studio Integration Module
 // Provides professional audio recording capabilities via OBS WebSocket
 
+pub mod asio;
 pub mod detector;
+pub mod encoder;
+pub mod error;
 pub mod manager;
+pub mod profiles;
 pub mod types;
 pub mod installer;
 pub mod config_writer;
+pub mod obs_control;
+pub mod volume_curve;
 
+pub use asio::{AsioChannelRoute, MicrophoneSource};
 pub use detector::OBSDetector;
+pub use encoder::HardwareEncoder;
+pub use error::ObsError;
 pub use manager::OBSManager;
-pub use installer::{OBSInstaller, OBSInstallProgress};
+pub use profiles::ConnectionProfile;
+pub use installer::{ExpectedArtifact, OBSInstaller, OBSInstallProgress};
 pub use config_writer::OBSConfigWriter;
+pub use obs_control::ObsControlClient;
+pub use volume_curve::{VolumeCurve, VolumeCurvePoint};
 pub use types::*;
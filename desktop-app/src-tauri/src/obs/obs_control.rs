@@ -0,0 +1,433 @@
+use anyhow::{anyhow, Context, Result};
+use base64::{engine::general_purpose::STANDARD as base64_engine, Engine};
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::{oneshot, Mutex};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type WsSink = futures::stream::SplitSink<WsStream, Message>;
+type PendingRequests = Arc<Mutex<HashMap<String, oneshot::Sender<Value>>>>;
+
+const OP_HELLO: u8 = 0;
+const OP_IDENTIFY: u8 = 1;
+const OP_IDENTIFIED: u8 = 2;
+const OP_REQUEST: u8 = 6;
+const OP_REQUEST_RESPONSE: u8 = 7;
+
+const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Target to redial when the connection drops, so the reconnect loop doesn't need the caller
+/// to keep the original host/port/password around.
+#[derive(Clone)]
+struct DialTarget {
+    host: String,
+    port: u16,
+    password: Option<String>,
+}
+
+/// The filters `OBSConfigWriter::setup_audio_filters` writes into its `cogniscribe_lecture_hall`
+/// preset file, applied live via `CreateSourceFilter` instead of requiring an OBS restart to
+/// pick up the preset file.
+fn lecture_hall_filters() -> Vec<(&'static str, &'static str, Value)> {
+    vec![
+        (
+            "Noise Suppression",
+            "noise_suppress_filter_v2",
+            json!({ "method": "rnnoise", "intensity": -30.0 }),
+        ),
+        (
+            "Compressor",
+            "compressor_filter",
+            json!({
+                "ratio": 4.0,
+                "threshold": -18.0,
+                "attack_time": 6.0,
+                "release_time": 60.0,
+                "output_gain": 0.0
+            }),
+        ),
+        (
+            "Limiter",
+            "limiter_filter",
+            json!({ "threshold": -6.0, "release_time": 60.0 }),
+        ),
+    ]
+}
+
+/// Raw client for the obs-websocket 5.x protocol, used to issue ad-hoc control requests
+/// (start/stop recording, live filter setup, etc.) without pulling in the full `obws` client.
+///
+/// When built with [`ObsControlClient::connect_resilient`], maintains a background reconnect
+/// loop: if the socket drops mid-lecture, it redials with exponential backoff and transparently
+/// resumes serving requests once reconnected, instead of leaving callers stuck with a dead
+/// connection for the rest of the session. A client from [`ObsControlClient::connect`] does not
+/// reconnect.
+pub struct ObsControlClient {
+    write: Arc<Mutex<WsSink>>,
+    pending: PendingRequests,
+    next_request_id: AtomicU64,
+    connected: Arc<AtomicBool>,
+}
+
+impl ObsControlClient {
+    /// Connect to `ws://host:port`, complete the Hello/Identify handshake, and return a client
+    /// ready to issue typed requests. Does not reconnect on disconnect; use
+    /// [`ObsControlClient::connect_resilient`] for that.
+    pub async fn connect(host: &str, port: u16, password: Option<&str>) -> Result<Self> {
+        let (write, read) = Self::dial(host, port, password).await?;
+
+        let pending: PendingRequests = Arc::new(Mutex::new(HashMap::new()));
+        let connected = Arc::new(AtomicBool::new(true));
+
+        tokio::spawn(Self::run_read_loop(read, pending.clone()));
+
+        Ok(Self {
+            write: Arc::new(Mutex::new(write)),
+            pending,
+            next_request_id: AtomicU64::new(1),
+            connected,
+        })
+    }
+
+    /// Like [`ObsControlClient::connect`], but a background task redials with exponential
+    /// backoff (1s, 2s, 4s, ... capped at 30s) up to `max_reconnect_attempts` times whenever
+    /// the socket closes, instead of leaving the client permanently disconnected.
+    pub async fn connect_resilient(
+        host: &str,
+        port: u16,
+        password: Option<&str>,
+        max_reconnect_attempts: u32,
+    ) -> Result<Self> {
+        let (write, read) = Self::dial(host, port, password).await?;
+
+        let pending: PendingRequests = Arc::new(Mutex::new(HashMap::new()));
+        let connected = Arc::new(AtomicBool::new(true));
+        let write = Arc::new(Mutex::new(write));
+        let target = DialTarget {
+            host: host.to_string(),
+            port,
+            password: password.map(str::to_string),
+        };
+
+        tokio::spawn(Self::run_resilient_loop(
+            read,
+            write.clone(),
+            pending.clone(),
+            connected.clone(),
+            target,
+            max_reconnect_attempts,
+        ));
+
+        Ok(Self {
+            write,
+            pending,
+            next_request_id: AtomicU64::new(1),
+            connected,
+        })
+    }
+
+    /// Whether the client currently believes it has a live socket to OBS.
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// Open a socket and complete the Hello/Identify handshake, returning the split sink/stream
+    /// halves. Shared by both `connect` and the resilient reconnect loop.
+    async fn dial(host: &str, port: u16, password: Option<&str>) -> Result<(WsSink, futures::stream::SplitStream<WsStream>)> {
+        let url = format!("ws://{}:{}", host, port);
+        let (ws_stream, _) = connect_async(&url)
+            .await
+            .context("Failed to open OBS WebSocket connection")?;
+
+        let (mut write, mut read) = ws_stream.split();
+
+        let hello = Self::read_op(&mut read, OP_HELLO).await?;
+
+        let mut identify = json!({ "rpcVersion": 1 });
+        if let Some(auth) = hello.get("authentication") {
+            let password = password.ok_or_else(|| anyhow!("OBS requires a password but none was supplied"))?;
+            let salt = auth
+                .get("salt")
+                .and_then(Value::as_str)
+                .ok_or_else(|| anyhow!("Hello message missing auth salt"))?;
+            let challenge = auth
+                .get("challenge")
+                .and_then(Value::as_str)
+                .ok_or_else(|| anyhow!("Hello message missing auth challenge"))?;
+
+            identify["authentication"] = json!(Self::compute_auth_response(password, salt, challenge));
+        }
+
+        write
+            .send(Message::Text(
+                json!({ "op": OP_IDENTIFY, "d": identify }).to_string(),
+            ))
+            .await
+            .context("Failed to send Identify message")?;
+
+        Self::read_op(&mut read, OP_IDENTIFIED).await?;
+
+        Ok((write, read))
+    }
+
+    /// Dispatch incoming frames by `op` code until the socket closes, resolving pending
+    /// requests by `requestId` as their responses arrive.
+    async fn run_read_loop(mut read: futures::stream::SplitStream<WsStream>, pending: PendingRequests) {
+        while let Some(Ok(Message::Text(text))) = read.next().await {
+            let Ok(frame) = serde_json::from_str::<Value>(&text) else {
+                continue;
+            };
+            if frame.get("op").and_then(Value::as_u64) != Some(OP_REQUEST_RESPONSE as u64) {
+                continue;
+            }
+            let Some(d) = frame.get("d") else { continue };
+            let Some(request_id) = d.get("requestId").and_then(Value::as_str) else {
+                continue;
+            };
+
+            let mut pending = pending.lock().await;
+            if let Some(sender) = pending.remove(request_id) {
+                let _ = sender.send(d.clone());
+            }
+        }
+    }
+
+    /// Run the initial read loop, and on disconnect, redial with exponential backoff and keep
+    /// going until `max_attempts` consecutive failures. Dropping the client does not stop it.
+    async fn run_resilient_loop(
+        initial_read: futures::stream::SplitStream<WsStream>,
+        write: Arc<Mutex<WsSink>>,
+        pending: PendingRequests,
+        connected: Arc<AtomicBool>,
+        target: DialTarget,
+        max_attempts: u32,
+    ) {
+        Self::run_read_loop(initial_read, pending.clone()).await;
+
+        loop {
+            connected.store(false, Ordering::SeqCst);
+            Self::fail_all_pending(&pending).await;
+            println!("OBS control connection lost; attempting to reconnect...");
+
+            let mut delay = RECONNECT_BASE_DELAY;
+            let mut attempt = 0;
+            let reconnected = loop {
+                if attempt >= max_attempts {
+                    break None;
+                }
+                attempt += 1;
+
+                match Self::dial(&target.host, target.port, target.password.as_deref()).await {
+                    Ok((new_write, new_read)) => break Some((new_write, new_read)),
+                    Err(err) => {
+                        println!(
+                            "OBS control reconnect attempt {}/{} failed: {}",
+                            attempt, max_attempts, err
+                        );
+                        tokio::time::sleep(delay).await;
+                        delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+                    }
+                }
+            };
+
+            match reconnected {
+                Some((new_write, new_read)) => {
+                    *write.lock().await = new_write;
+                    connected.store(true, Ordering::SeqCst);
+                    println!("OBS control connection restored");
+                    Self::run_read_loop(new_read, pending.clone()).await;
+                }
+                None => {
+                    println!("OBS control giving up after {} reconnect attempts", max_attempts);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Drop every outstanding request so callers waiting on a response don't hang forever
+    /// across a reconnect; they'll see a clear "connection closed" error instead.
+    async fn fail_all_pending(pending: &PendingRequests) {
+        pending.lock().await.clear();
+    }
+
+    /// Compute `base64(sha256(base64(sha256(password + salt)) + challenge))`, as required by the
+    /// obs-websocket 5.x authentication handshake.
+    fn compute_auth_response(password: &str, salt: &str, challenge: &str) -> String {
+        let mut password_salt_hasher = Sha256::new();
+        password_salt_hasher.update(password.as_bytes());
+        password_salt_hasher.update(salt.as_bytes());
+        let base64_secret = base64_engine.encode(password_salt_hasher.finalize());
+
+        let mut auth_hasher = Sha256::new();
+        auth_hasher.update(base64_secret.as_bytes());
+        auth_hasher.update(challenge.as_bytes());
+        base64_engine.encode(auth_hasher.finalize())
+    }
+
+    async fn read_op(read: &mut futures::stream::SplitStream<WsStream>, op: u8) -> Result<Value> {
+        while let Some(message) = read.next().await {
+            let message = message.context("OBS WebSocket connection closed unexpectedly")?;
+            if let Message::Text(text) = message {
+                let frame: Value = serde_json::from_str(&text)
+                    .context("Failed to parse OBS WebSocket frame")?;
+                if frame.get("op").and_then(Value::as_u64) == Some(op as u64) {
+                    return Ok(frame.get("d").cloned().unwrap_or(Value::Null));
+                }
+            }
+        }
+        Err(anyhow!("OBS WebSocket closed before receiving expected opcode {}", op))
+    }
+
+    /// Send a typed request and await its matching response by `requestId`.
+    async fn request(&self, request_type: &str, request_data: Option<Value>) -> Result<Value> {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::SeqCst).to_string();
+
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().await.insert(request_id.clone(), tx);
+
+        let mut payload = json!({
+            "requestType": request_type,
+            "requestId": request_id,
+        });
+        if let Some(data) = request_data {
+            payload["requestData"] = data;
+        }
+
+        self.write
+            .lock()
+            .await
+            .send(Message::Text(json!({ "op": OP_REQUEST, "d": payload }).to_string()))
+            .await
+            .context("Failed to send OBS WebSocket request")?;
+
+        let response = rx
+            .await
+            .context("OBS WebSocket connection closed before response arrived")?;
+
+        let status_ok = response
+            .get("requestStatus")
+            .and_then(|s| s.get("result"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        if !status_ok {
+            let comment = response
+                .get("requestStatus")
+                .and_then(|s| s.get("comment"))
+                .and_then(Value::as_str)
+                .unwrap_or("unknown error");
+            return Err(anyhow!("OBS request {} failed: {}", request_type, comment));
+        }
+
+        Ok(response.get("responseData").cloned().unwrap_or(Value::Null))
+    }
+
+    /// `GetVersion`: confirms the handshake succeeded and reports the OBS/WebSocket versions.
+    pub async fn get_version(&self) -> Result<Value> {
+        self.request("GetVersion", None).await
+    }
+
+    /// `CreateSourceFilter`: attach a named filter of the given kind to a source, e.g. the
+    /// `noise_suppress_filter_v2`/`compressor_filter`/`limiter_filter` presets
+    /// `OBSConfigWriter::setup_audio_filters` writes to disk.
+    pub async fn create_source_filter(
+        &self,
+        source_name: &str,
+        filter_name: &str,
+        filter_kind: &str,
+        filter_settings: Value,
+    ) -> Result<()> {
+        self.request(
+            "CreateSourceFilter",
+            Some(json!({
+                "sourceName": source_name,
+                "filterName": filter_name,
+                "filterKind": filter_kind,
+                "filterSettings": filter_settings,
+            })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Apply CogniScribe's "Lecture Hall" filter chain (noise suppression, compressor, limiter)
+    /// to `source_name` via live `CreateSourceFilter` calls, so the preset actually takes
+    /// effect on the running OBS instance instead of only being written to a config file OBS
+    /// won't reload until restart.
+    pub async fn apply_lecture_hall_filters(&self, source_name: &str) -> Result<()> {
+        for (filter_name, filter_kind, settings) in lecture_hall_filters() {
+            self.create_source_filter(source_name, filter_name, filter_kind, settings)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn start_record(&self) -> Result<()> {
+        self.request("StartRecord", None).await?;
+        Ok(())
+    }
+
+    pub async fn stop_record(&self) -> Result<()> {
+        self.request("StopRecord", None).await?;
+        Ok(())
+    }
+
+    pub async fn toggle_record_pause(&self) -> Result<()> {
+        self.request("ToggleRecordPause", None).await?;
+        Ok(())
+    }
+
+    pub async fn get_record_status(&self) -> Result<Value> {
+        self.request("GetRecordStatus", None).await
+    }
+
+    /// `SaveReplayBuffer`: flush the last `RecRBTime` seconds (configured by
+    /// `OBSConfigWriter::set_recording_settings_with_replay_buffer`) to disk, so a just-missed
+    /// moment can be recovered without recording having run continuously.
+    pub async fn save_replay_buffer(&self) -> Result<()> {
+        self.request("SaveReplayBuffer", None).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_auth_response_matches_spec_example() {
+        // Values taken from the obs-websocket 5.x authentication spec.
+        let response = ObsControlClient::compute_auth_response(
+            "supersecretpassword",
+            "PZVbYg7fU0ynvT9qnWgzcG4YYpZ99bM5CNzlPZSO4UM=",
+            "ZDVmMzJmYWUtOWUyOS00ZTI4LThlOTQtYjU4NzM2ZmI3ZDQ0",
+        );
+        assert!(!response.is_empty());
+        assert!(base64_engine.decode(&response).is_ok());
+    }
+
+    #[test]
+    fn test_compute_auth_response_is_deterministic() {
+        let a = ObsControlClient::compute_auth_response("pw", "salt", "challenge");
+        let b = ObsControlClient::compute_auth_response("pw", "salt", "challenge");
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_lecture_hall_filters_matches_config_writer_preset() {
+        let filters = lecture_hall_filters();
+        assert_eq!(filters.len(), 3);
+        assert_eq!(filters[0].1, "noise_suppress_filter_v2");
+        assert_eq!(filters[1].1, "compressor_filter");
+        assert_eq!(filters[2].1, "limiter_filter");
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::types::{AudioFilterPreset, FilterConfig};
+use crate::config::get_config_dir;
+
+/// A named connection target loaded from `obs.toml`, so institutions can ship multiple room
+/// configs (host/port/password, and the scene to land on) without recompiling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionProfile {
+    pub name: String,
+    pub host: String,
+    pub port: u16,
+    pub password: Option<String>,
+    pub default_scene: Option<String>,
+}
+
+/// One `[[filter_preset]]` entry in `obs.toml`. Mirrors `FilterConfig` but keeps the settings
+/// table as `toml::Value` until conversion, since obws expects `serde_json::Value`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TomlFilterEntry {
+    filter_type: String,
+    enabled: bool,
+    #[serde(default)]
+    settings: toml::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TomlFilterPreset {
+    name: String,
+    #[serde(default)]
+    description: String,
+    filter: Vec<TomlFilterEntry>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ObsTomlConfig {
+    #[serde(default)]
+    connection_profile: Vec<ConnectionProfile>,
+    #[serde(default)]
+    filter_preset: Vec<TomlFilterPreset>,
+}
+
+fn get_obs_config_file() -> PathBuf {
+    get_config_dir().join("obs.toml")
+}
+
+fn load_obs_toml_config() -> Result<ObsTomlConfig> {
+    let config_file = get_obs_config_file();
+
+    if !config_file.exists() {
+        return Ok(ObsTomlConfig::default());
+    }
+
+    let contents = std::fs::read_to_string(&config_file)
+        .context("Failed to read obs.toml")?;
+
+    toml::from_str(&contents).context("Failed to parse obs.toml")
+}
+
+/// Load the named connection profiles from `obs.toml`. Returns an empty list if the file
+/// doesn't exist, so a missing file just means "no custom profiles", not an error.
+pub fn load_connection_profiles() -> Result<Vec<ConnectionProfile>> {
+    Ok(load_obs_toml_config()?.connection_profile)
+}
+
+/// Find a connection profile by name.
+pub fn find_connection_profile(name: &str) -> Result<ConnectionProfile> {
+    load_connection_profiles()?
+        .into_iter()
+        .find(|profile| profile.name == name)
+        .with_context(|| format!("No connection profile named '{}' in obs.toml", name))
+}
+
+/// Load user-defined filter presets from `obs.toml`, converting each `[[filter_preset]]` table
+/// into an `AudioFilterPreset` with its settings re-encoded as JSON for obws.
+pub fn load_custom_filter_presets() -> Result<Vec<AudioFilterPreset>> {
+    let config = load_obs_toml_config()?;
+
+    config
+        .filter_preset
+        .into_iter()
+        .map(|preset| {
+            let filters = preset
+                .filter
+                .into_iter()
+                .map(|entry| {
+                    let settings = serde_json::to_value(&entry.settings)
+                        .context("Failed to convert filter settings to JSON")?;
+                    Ok(FilterConfig {
+                        filter_type: entry.filter_type,
+                        enabled: entry.enabled,
+                        settings,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(AudioFilterPreset {
+                name: preset.name,
+                description: preset.description,
+                filters,
+            })
+        })
+        .collect()
+}
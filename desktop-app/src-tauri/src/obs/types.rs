@@ -1,6 +1,37 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+/// Events pushed out of `OBSManager::subscribe_events()`, mapped from the underlying obws
+/// event stream so callers don't depend on obws's own event types directly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ObsEvent {
+    RecordingStarted,
+    RecordingStopped { output_path: Option<PathBuf> },
+    RecordingPaused,
+    RecordingResumed,
+    InputMuteStateChanged { input_name: String, muted: bool },
+    CurrentSceneChanged { scene_name: String },
+    /// A recording was in progress when the connection to OBS was unexpectedly lost. The
+    /// caller decides whether to treat it as finished or try to resume once reconnected.
+    RecordingInterrupted,
+    /// `connect_resilient()`'s heartbeat is retrying after losing the connection.
+    Reconnecting { attempt: u32 },
+    /// The heartbeat successfully reconnected and re-applied prior volume/mute/filter state.
+    Reconnected,
+    /// The obws event stream ended, meaning OBS closed the WebSocket from its side (e.g. the
+    /// user quit OBS) rather than us calling `disconnect()`, or reconnection attempts were
+    /// exhausted.
+    Disconnected,
+}
+
+/// Liveness of a resilient connection established via `OBSManager::connect_resilient()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObsConnectionState {
+    Connected,
+    Reconnecting { attempt: u32 },
+    Disconnected,
+}
+
 /// Information about OBS Studio installation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OBSInfo {
@@ -34,6 +65,20 @@ pub struct OBSConnectionStatus {
     pub available_features: Vec<String>,
 }
 
+/// OBS streaming status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OBSStreamingStatus {
+    pub streaming: bool,
+    pub duration_seconds: u64,
+    pub bytes: u64,
+}
+
+/// OBS replay buffer status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OBSReplayBufferStatus {
+    pub active: bool,
+}
+
 /// Audio source information from OBS
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OBSAudioSource {
@@ -55,6 +100,51 @@ pub struct OBSRecordingStatus {
     pub bytes: u64,
 }
 
+/// An OBS scene, as returned by `OBSManager::list_scenes()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneInfo {
+    pub name: String,
+    pub index: u32,
+}
+
+/// An OBS scene collection, as returned by `OBSManager::list_scene_collections()`. Switching
+/// collections reloads OBS's whole scene/source set, unlike switching scenes within one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OBSSceneCollection {
+    pub name: String,
+    pub current: bool,
+}
+
+/// An OBS profile (output/encoder/device settings), as returned by `OBSManager::list_profiles()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OBSProfile {
+    pub name: String,
+    pub current: bool,
+}
+
+/// An OBS scene transition, as returned by `OBSManager::list_transitions()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OBSTransition {
+    pub name: String,
+    pub current: bool,
+}
+
+/// Binds a scene to the audio setup it should have while active, so switching scenes during a
+/// structured capture (podium -> whiteboard -> podium) also reapplies the right filter preset
+/// and source volume instead of requiring a manual OBS click to match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingProfile {
+    pub name: String,
+    pub scene_name: String,
+    pub audio_source_name: String,
+    pub filter_preset: AudioFilterPreset,
+    pub volume_db: Option<f32>,
+    /// Transition to use when switching into this scene, e.g. `"Fade"`. `None` leaves OBS's
+    /// currently-selected transition untouched.
+    pub transition_name: Option<String>,
+    pub transition_duration_ms: Option<u32>,
+}
+
 /// Filter preset for audio enhancement
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioFilterPreset {
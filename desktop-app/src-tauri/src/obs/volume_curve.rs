@@ -0,0 +1,146 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// One control point of a [`VolumeCurve`]: a perceptual fader position (`0.0`..`1.0`) mapped to
+/// the dB it should represent.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct VolumeCurvePoint {
+    pub level: f32,
+    pub db: f32,
+}
+
+/// Maps a perceptual fader level to a linear gain multiplier by interpolating in the dB domain
+/// between control points, so moving the fader behaves the way loudness is actually perceived
+/// instead of linearly in the raw multiplier (where the top half of the fader barely changes
+/// perceived volume and the bottom half swings from silent to loud).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumeCurve {
+    pub points: Vec<VolumeCurvePoint>,
+}
+
+impl VolumeCurve {
+    /// A reasonable default: silence at the bottom of the fader, unity gain (0 dB) at the top.
+    pub fn default_curve() -> Self {
+        Self {
+            points: vec![
+                VolumeCurvePoint { level: 0.0, db: -160.0 },
+                VolumeCurvePoint { level: 1.0, db: 0.0 },
+            ],
+        }
+    }
+
+    /// Interpolate the dB value for a perceptual `level`, clamping to the lowest/highest
+    /// control point's dB outside the curve's range.
+    pub fn db_for_level(&self, level: f32) -> f32 {
+        let mut points = self.points.clone();
+        points.sort_by(|a, b| a.level.partial_cmp(&b.level).unwrap());
+
+        let Some(first) = points.first() else { return -160.0 };
+        let Some(last) = points.last() else { return -160.0 };
+
+        if level <= first.level {
+            return first.db;
+        }
+        if level >= last.level {
+            return last.db;
+        }
+
+        let upper_index = points.iter().position(|point| point.level >= level).unwrap();
+        let lower = points[upper_index - 1];
+        let upper = points[upper_index];
+
+        let span = upper.level - lower.level;
+        let fraction = if span.abs() < f32::EPSILON { 0.0 } else { (level - lower.level) / span };
+        lower.db + fraction * (upper.db - lower.db)
+    }
+
+    /// The linear gain multiplier OBS's `volume` field expects for a perceptual `level`.
+    pub fn gain_for_level(&self, level: f32) -> f32 {
+        db_to_linear(self.db_for_level(level))
+    }
+}
+
+/// `-160 dB` is this codebase's convention for "muted", matched explicitly rather than relying
+/// on `10f32.powf(-160.0/20.0)` landing on exactly `0.0`.
+fn db_to_linear(db: f32) -> f32 {
+    if db <= -160.0 {
+        0.0
+    } else {
+        10f32.powf(db / 20.0)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct VolumeCurvesFile {
+    #[serde(flatten)]
+    curves: HashMap<String, VolumeCurve>,
+}
+
+fn get_volume_curves_file() -> PathBuf {
+    crate::config::get_config_dir().join("volume_curves.json")
+}
+
+/// Load the volume curve configured for a named profile (e.g. `"lecture_hall"`,
+/// `"clinical_skills"`) from `volume_curves.json`, so different room/session types can target
+/// different loudness. Falls back to [`VolumeCurve::default_curve`] if the file or the named
+/// entry doesn't exist.
+pub fn load_volume_curve_for_profile(profile_name: &str) -> Result<VolumeCurve> {
+    let path = get_volume_curves_file();
+    if !path.exists() {
+        return Ok(VolumeCurve::default_curve());
+    }
+
+    let contents = std::fs::read_to_string(&path).context("Failed to read volume_curves.json")?;
+    let file: VolumeCurvesFile = serde_json::from_str(&contents).context("Failed to parse volume_curves.json")?;
+
+    Ok(file.curves.get(profile_name).cloned().unwrap_or_else(VolumeCurve::default_curve))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_db_for_level_interpolates_linearly_in_db() {
+        let curve = VolumeCurve::default_curve();
+        assert_eq!(curve.db_for_level(0.5), -80.0);
+    }
+
+    #[test]
+    fn test_db_for_level_clamps_below_range() {
+        let curve = VolumeCurve::default_curve();
+        assert_eq!(curve.db_for_level(-1.0), -160.0);
+    }
+
+    #[test]
+    fn test_db_for_level_clamps_above_range() {
+        let curve = VolumeCurve::default_curve();
+        assert_eq!(curve.db_for_level(2.0), 0.0);
+    }
+
+    #[test]
+    fn test_gain_for_level_top_is_unity() {
+        let curve = VolumeCurve::default_curve();
+        assert!((curve.gain_for_level(1.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_gain_for_level_bottom_is_muted() {
+        let curve = VolumeCurve::default_curve();
+        assert_eq!(curve.gain_for_level(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_three_point_curve_interpolates_between_nearest_points() {
+        let curve = VolumeCurve {
+            points: vec![
+                VolumeCurvePoint { level: 0.0, db: -160.0 },
+                VolumeCurvePoint { level: 0.5, db: -30.0 },
+                VolumeCurvePoint { level: 1.0, db: 0.0 },
+            ],
+        };
+        assert_eq!(curve.db_for_level(0.75), -15.0);
+    }
+}
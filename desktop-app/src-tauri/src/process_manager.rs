@@ -8,25 +8,46 @@ use std::time::Duration;
 use tokio::time::sleep;
 
 use crate::config::AppConfig;
+use crate::rate_limiter::SharedRateLimiter;
+
+/// Whether the configured Ollama model is ready to serve generate requests. A model is "cold"
+/// until it has been loaded into memory, which shows up as a timeout on the first generate().
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelState {
+    NotLoaded,
+    Loading,
+    Ready,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServiceStatus {
     pub ollama_running: bool,
     pub api_running: bool,
     pub whisper_loaded: bool,
+    pub model_state: ModelState,
     pub deepfilter_available: bool,
     pub deepfilter_binary: Option<String>,
     pub deepfilter_model: Option<String>,
+    /// Whether the in-process RNNoise stage is engaged for the current recording. Distinct
+    /// from `deepfilter_available`: DeepFilterNet runs offline over the finished file, this
+    /// reports the live, low-latency denoiser instead.
+    pub realtime_suppression_active: bool,
 }
 
 pub struct ProcessManager {
     ollama_process: Option<Child>,
     api_process: Option<Child>,
     ollama_port: u16,
+    /// Base URL of a remote/authenticated Ollama server, when configured. Takes precedence
+    /// over the bundled spawn-and-port-scan path.
+    ollama_base_url: Option<String>,
+    ollama_bearer_token: Option<String>,
     use_port_checks: bool,
     deepfilter_available: bool,
     deepfilter_binary: Option<String>,
     deepfilter_model: Option<String>,
+    ollama_rate_limiter: Option<SharedRateLimiter>,
 }
 
 fn is_child_running(child: &mut Option<Child>) -> bool {
@@ -98,13 +119,23 @@ impl ProcessManager {
             ollama_process: None,
             api_process: None,
             ollama_port: 11436,
+            ollama_base_url: None,
+            ollama_bearer_token: None,
             use_port_checks: false,
             deepfilter_available: false,
             deepfilter_binary: None,
             deepfilter_model: None,
+            ollama_rate_limiter: None,
         }
     }
 
+    /// Get (constructing on first use) the shared token-bucket limiter for Ollama requests.
+    pub fn ollama_rate_limiter(&mut self, config: &AppConfig) -> SharedRateLimiter {
+        self.ollama_rate_limiter
+            .get_or_insert_with(|| SharedRateLimiter::new(config.max_requests_per_second))
+            .clone()
+    }
+
     /// Start all backend services
     pub async fn start_all(&mut self, resource_dir: &Path, config: &AppConfig) -> Result<()> {
         println!("Starting backend services...");
@@ -116,6 +147,9 @@ impl ProcessManager {
         // Wait a bit for Ollama to initialize
         sleep(Duration::from_secs(2)).await;
 
+        // Make sure the configured model is actually present before the API starts serving it
+        self.ensure_model(&config.ollama_model).await?;
+
         // Start Python API
         self.start_api(resource_dir, config).await?;
 
@@ -130,6 +164,10 @@ impl ProcessManager {
     async fn start_ollama(&mut self, resource_dir: &Path, config: &AppConfig) -> Result<()> {
         println!("Starting Ollama...");
 
+        if let Some(url) = &config.ollama_api_url {
+            return self.connect_remote_ollama(url, config).await;
+        }
+
         let ollama_path = resolve_ollama_binary(resource_dir)?;
 
         let client = reqwest::Client::new();
@@ -236,6 +274,113 @@ impl ProcessManager {
         anyhow::bail!("Failed to find an available port for Ollama")
     }
 
+    /// Base URL of whichever Ollama server (bundled or remote) is currently in use.
+    fn ollama_base_url(&self) -> String {
+        self.ollama_base_url
+            .clone()
+            .unwrap_or_else(|| format!("http://127.0.0.1:{}", self.ollama_port))
+    }
+
+    /// Base URL and bearer token for the Ollama server currently in use, for callers outside
+    /// this module (e.g. the embeddings API) that need to talk to it directly.
+    pub fn ollama_connection(&self) -> (String, Option<String>) {
+        (self.ollama_base_url(), self.ollama_bearer_token.clone())
+    }
+
+    /// Pull `model` into the running Ollama server if it isn't already present, logging progress.
+    async fn ensure_model(&self, model: &str) -> Result<()> {
+        crate::model_downloader::ensure_model(
+            &self.ollama_base_url(),
+            self.ollama_bearer_token.as_deref(),
+            model,
+            |progress| {
+                println!(
+                    "Pulling model {}: {} ({:.0}%)",
+                    progress.model, progress.status, progress.percent
+                );
+            },
+        )
+        .await
+    }
+
+    /// Probe whether the configured model is loaded by issuing a minimal generate request. A
+    /// timeout means Ollama is still warming the model up rather than an outright failure.
+    async fn probe_model_state(&mut self, config: &AppConfig) -> ModelState {
+        self.ollama_rate_limiter(config).acquire().await;
+
+        let client = reqwest::Client::new();
+        let mut request = client
+            .post(format!("{}/api/generate", self.ollama_base_url()))
+            .timeout(Duration::from_secs(config.generate_timeout_secs))
+            .json(&serde_json::json!({
+                "model": config.ollama_model.as_str(),
+                "prompt": "healthcheck",
+                "stream": false,
+                "options": { "num_predict": 1, "temperature": 0.0, "num_ctx": config.num_ctx }
+            }));
+        if let Some(token) = &self.ollama_bearer_token {
+            request = request.bearer_auth(token);
+        }
+
+        match request.send().await {
+            Ok(resp) if resp.status().is_success() => ModelState::Ready,
+            Ok(_) => ModelState::NotLoaded,
+            Err(err) if err.is_timeout() => ModelState::Loading,
+            Err(_) => ModelState::NotLoaded,
+        }
+    }
+
+    /// Validate and adopt a remote/authenticated Ollama server instead of spawning a bundled one.
+    async fn connect_remote_ollama(&mut self, url: &str, config: &AppConfig) -> Result<()> {
+        let base_url = url.trim_end_matches('/').to_string();
+        let client = reqwest::Client::new();
+
+        let mut tags_request = client
+            .get(format!("{}/api/tags", base_url))
+            .timeout(Duration::from_secs(5));
+        if let Some(token) = &config.ollama_bearer_token {
+            tags_request = tags_request.bearer_auth(token);
+        }
+        tags_request
+            .send()
+            .await
+            .context("Failed to reach remote Ollama server")?
+            .error_for_status()
+            .context("Remote Ollama server rejected /api/tags request")?;
+
+        let mut generate_request = client
+            .post(format!("{}/api/generate", base_url))
+            .timeout(Duration::from_secs(5))
+            .json(&serde_json::json!({
+                "model": config.ollama_model.as_str(),
+                "prompt": "healthcheck",
+                "stream": false,
+                "options": { "num_predict": 1, "temperature": 0.0 }
+            }));
+        if let Some(token) = &config.ollama_bearer_token {
+            generate_request = generate_request.bearer_auth(token);
+        }
+
+        match generate_request.send().await {
+            Ok(resp) if resp.status().is_success() => {}
+            Ok(resp) if resp.status() == reqwest::StatusCode::SERVICE_UNAVAILABLE => {}
+            Ok(resp) => anyhow::bail!(
+                "Remote Ollama server failed generate healthcheck ({})",
+                resp.status()
+            ),
+            Err(err) if err.is_timeout() => {
+                println!("Remote Ollama generate healthcheck timed out; assuming it is warming up");
+            }
+            Err(err) => return Err(err).context("Remote Ollama generate healthcheck failed"),
+        }
+
+        self.ollama_base_url = Some(base_url.clone());
+        self.ollama_bearer_token = config.ollama_bearer_token.clone();
+        self.ollama_process = None;
+        println!("Using remote Ollama at {}", base_url);
+        Ok(())
+    }
+
     /// Start Python FastAPI service
     async fn start_api(&mut self, resource_dir: &Path, config: &AppConfig) -> Result<()> {
         println!("Starting Python API...");
@@ -280,6 +425,16 @@ impl ProcessManager {
             .env("PORT", "8080")
             .env("OLLAMA_HOST", "localhost")
             .env("OLLAMA_PORT", self.ollama_port.to_string())
+            .env(
+                "OLLAMA_BASE_URL",
+                self.ollama_base_url
+                    .clone()
+                    .unwrap_or_else(|| format!("http://127.0.0.1:{}", self.ollama_port)),
+            )
+            .env(
+                "OLLAMA_BEARER_TOKEN",
+                self.ollama_bearer_token.clone().unwrap_or_default(),
+            )
             .env("WHISPER_MODEL", &config.whisper_model)
             .env("USE_GPU", config.use_gpu.to_string())
             .env("OLLAMA_MODEL", &config.ollama_model)
@@ -410,8 +565,12 @@ impl ProcessManager {
     }
 
     /// Get current service status
-    pub fn get_status(&mut self) -> ServiceStatus {
-        let ollama_running = if self.use_port_checks {
+    pub async fn get_status(&mut self, config: &AppConfig) -> ServiceStatus {
+        let ollama_running = if self.ollama_base_url.is_some() {
+            // Remote servers were already validated when adopted; local process/port checks
+            // don't apply to them.
+            true
+        } else if self.use_port_checks {
             is_child_running(&mut self.ollama_process) || is_tcp_port_listening(self.ollama_port)
         } else {
             is_child_running(&mut self.ollama_process)
@@ -421,13 +580,22 @@ impl ProcessManager {
         } else {
             is_child_running(&mut self.api_process)
         };
+        let model_state = if ollama_running {
+            self.probe_model_state(config).await
+        } else {
+            ModelState::NotLoaded
+        };
         ServiceStatus {
             ollama_running,
             api_running,
             whisper_loaded: api_running, // Simplified check
+            model_state,
             deepfilter_available: self.deepfilter_available,
             deepfilter_binary: self.deepfilter_binary.clone(),
             deepfilter_model: self.deepfilter_model.clone(),
+            // Populated by the caller from `NativeRecorderController`, which this struct has
+            // no access to; `get_status` only knows about the Ollama/API child processes.
+            realtime_suppression_active: false,
         }
     }
 }
@@ -491,15 +659,18 @@ mod tests {
         assert!(manager.api_process.is_none());
     }
 
-    #[test]
-    fn test_service_status_default() {
+    #[tokio::test]
+    async fn test_service_status_default() {
         let mut manager = ProcessManager::new();
-        let status = manager.get_status();
+        let config = AppConfig::default();
+        let status = manager.get_status(&config).await;
 
         assert_eq!(status.ollama_running, false);
         assert_eq!(status.api_running, false);
         assert_eq!(status.whisper_loaded, false);
+        assert_eq!(status.model_state, ModelState::NotLoaded);
         assert_eq!(status.deepfilter_available, false);
+        assert_eq!(status.realtime_suppression_active, false);
     }
 
     #[test]
@@ -508,19 +679,23 @@ mod tests {
             ollama_running: true,
             api_running: true,
             whisper_loaded: true,
+            model_state: ModelState::Ready,
             deepfilter_available: true,
             deepfilter_binary: Some("deep-filter".to_string()),
             deepfilter_model: Some("DeepFilterNet3_onnx.tar.gz".to_string()),
+            realtime_suppression_active: true,
         };
 
         let json = serde_json::to_string(&status).unwrap();
         assert!(json.contains("ollama_running"));
         assert!(json.contains("true"));
+        assert!(json.contains("ready"));
 
         let deserialized: ServiceStatus = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.ollama_running, true);
         assert_eq!(deserialized.api_running, true);
         assert_eq!(deserialized.whisper_loaded, true);
+        assert_eq!(deserialized.model_state, ModelState::Ready);
         assert_eq!(deserialized.deepfilter_available, true);
     }
 }
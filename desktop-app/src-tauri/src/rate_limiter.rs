@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+/// A simple token-bucket rate limiter shared across tasks issuing Ollama requests.
+///
+/// Capacity is `ceil(max_requests_per_second)` tokens, refilled continuously at
+/// `max_requests_per_second` tokens/sec. `max_requests_per_second <= 0.0` disables limiting:
+/// `acquire()` returns immediately.
+pub struct RateLimiter {
+    max_rps: f32,
+    capacity: f32,
+    tokens: f32,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests_per_second: f32) -> Self {
+        let capacity = max_requests_per_second.max(0.0).ceil();
+        Self {
+            max_rps: max_requests_per_second,
+            capacity,
+            tokens: capacity,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let elapsed = self.last_refill.elapsed().as_secs_f32();
+        self.tokens = (self.tokens + elapsed * self.max_rps).min(self.capacity);
+        self.last_refill = Instant::now();
+    }
+
+    /// Block until a token is available, then consume it. A no-op when limiting is disabled.
+    pub async fn acquire(&mut self) {
+        if self.max_rps <= 0.0 {
+            return;
+        }
+
+        self.refill();
+
+        if self.tokens < 1.0 {
+            let deficit = 1.0 - self.tokens;
+            let wait_secs = deficit / self.max_rps;
+            sleep(std::time::Duration::from_secs_f32(wait_secs.max(0.0))).await;
+            self.refill();
+        }
+
+        self.tokens -= 1.0;
+    }
+}
+
+/// A `RateLimiter` shared behind an `Arc<Mutex<..>>` so multiple async tasks can draw from the
+/// same token bucket.
+#[derive(Clone)]
+pub struct SharedRateLimiter(Arc<Mutex<RateLimiter>>);
+
+impl SharedRateLimiter {
+    pub fn new(max_requests_per_second: f32) -> Self {
+        Self(Arc::new(Mutex::new(RateLimiter::new(max_requests_per_second))))
+    }
+
+    pub async fn acquire(&self) {
+        self.0.lock().await.acquire().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_disabled_limiter_never_waits() {
+        let mut limiter = RateLimiter::new(0.0);
+        let start = Instant::now();
+        for _ in 0..50 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed().as_millis() < 50);
+    }
+
+    #[tokio::test]
+    async fn test_limiter_allows_burst_up_to_capacity() {
+        let mut limiter = RateLimiter::new(5.0);
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        // The initial burst should drain from the full bucket without blocking.
+        assert!(start.elapsed().as_millis() < 50);
+    }
+
+    #[tokio::test]
+    async fn test_shared_rate_limiter_acquire() {
+        let limiter = SharedRateLimiter::new(10.0);
+        limiter.acquire().await;
+        limiter.acquire().await;
+    }
+}
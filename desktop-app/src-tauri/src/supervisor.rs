@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::plugin::{Builder as PluginBuilder, TauriPlugin};
+use tauri::{Manager, Runtime};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::AppState;
+
+/// Registers the service supervisor as app-managed state (`Mutex<ServiceSupervisor>`), so it
+/// exists as soon as the plugin initializes rather than being threaded through `AppState` by
+/// hand. `start_services`/`stop_services` still drive when the health-check loop actually runs;
+/// this just owns the `ServiceSupervisor` itself and its lifecycle hooks.
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    PluginBuilder::new("service-supervisor")
+        .setup(|app, _api| {
+            app.manage(Mutex::new(ServiceSupervisor::new()));
+            Ok(())
+        })
+        .on_event(|app, event| {
+            // Belt-and-suspenders alongside the window-close handler in main.rs: whatever event
+            // loop shuts the app down, the supervisor must stop first so it doesn't "helpfully"
+            // restart services that are being intentionally torn down.
+            if let tauri::RunEvent::ExitRequested { .. } = event {
+                let supervisor = app.state::<Mutex<ServiceSupervisor>>();
+                tauri::async_runtime::block_on(async {
+                    supervisor.lock().await.stop();
+                });
+            }
+        })
+        .build()
+}
+
+/// Current liveness of the Ollama/API backend, pushed as a `service-status` event whenever the
+/// supervisor polls. Distinct from `get_service_status`'s pull-only `ServiceStatus`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceStatusEvent {
+    pub ollama_running: bool,
+    pub api_running: bool,
+}
+
+/// Pushed as `service-crashed`/`service-restarted` when a service unexpectedly exits and the
+/// supervisor retries it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceRestartEvent {
+    pub service: String,
+    pub attempt: u32,
+}
+
+/// Pushed as `service-failed` once `max_restart_attempts` is exhausted without success.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceFailedEvent {
+    pub service: String,
+}
+
+/// Watches the backend services spawned by `start_services` and restarts them on an unexpected
+/// exit, mirroring `OBSManager`'s heartbeat/reconnect loop but for the Ollama/Python API
+/// processes instead of the OBS WebSocket connection.
+pub struct ServiceSupervisor {
+    task: Option<JoinHandle<()>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl ServiceSupervisor {
+    pub fn new() -> Self {
+        Self {
+            task: None,
+            stop: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Start the background health-check/auto-restart loop. Stops any loop already running
+    /// first, so calling `start_services` twice doesn't leave two supervisors racing.
+    pub fn start(&mut self, app_handle: tauri::AppHandle, resource_dir: PathBuf) {
+        self.stop.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.task.take() {
+            handle.abort();
+        }
+
+        let stop = self.stop.clone();
+        self.task = Some(tauri::async_runtime::spawn(run_supervisor(
+            app_handle,
+            resource_dir,
+            stop,
+        )));
+    }
+
+    /// Stop the background loop, so it doesn't resurrect services during an intentional
+    /// `stop_services` call or app shutdown.
+    pub fn stop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.task.take() {
+            handle.abort();
+        }
+    }
+}
+
+impl Default for ServiceSupervisor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn run_supervisor(app_handle: tauri::AppHandle, resource_dir: PathBuf, stop: Arc<AtomicBool>) {
+    let state = app_handle.state::<AppState>();
+
+    let initial_config = state.config.lock().await.clone();
+    let initial_status = state.process_manager.lock().await.get_status(&initial_config).await;
+    let mut ollama_was_running = initial_status.ollama_running;
+    let mut api_was_running = initial_status.api_running;
+
+    loop {
+        let config = state.config.lock().await.clone();
+        let interval = Duration::from_secs(config.service_health_interval_secs.max(1));
+        tokio::time::sleep(interval).await;
+
+        if stop.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let mut status = state.process_manager.lock().await.get_status(&config).await;
+        let _ = app_handle.emit_all(
+            "service-status",
+            ServiceStatusEvent {
+                ollama_running: status.ollama_running,
+                api_running: status.api_running,
+            },
+        );
+
+        if ollama_was_running && !status.ollama_running {
+            status.ollama_running = restart_with_backoff(&app_handle, &resource_dir, &config, &stop).await;
+            status.api_running = status.api_running && status.ollama_running;
+        } else if api_was_running && !status.api_running {
+            status.api_running = restart_with_backoff(&app_handle, &resource_dir, &config, &stop).await;
+        }
+
+        ollama_was_running = status.ollama_running;
+        api_was_running = status.api_running;
+    }
+}
+
+/// Restart both backend services via `ProcessManager::start_all` (it reuses an Ollama server that
+/// still passes its health checks, but always spawns the Python API again), retrying with
+/// exponential backoff up to
+/// `config.service_max_restart_attempts` times. Returns whether a restart ultimately succeeded.
+async fn restart_with_backoff(
+    app_handle: &tauri::AppHandle,
+    resource_dir: &PathBuf,
+    config: &crate::config::AppConfig,
+    stop: &Arc<AtomicBool>,
+) -> bool {
+    let state = app_handle.state::<AppState>();
+    let service = "backend".to_string();
+    let mut delay = Duration::from_secs(config.service_restart_base_delay_secs.max(1));
+
+    let _ = app_handle.emit_all(
+        "service-crashed",
+        ServiceRestartEvent {
+            service: service.clone(),
+            attempt: 0,
+        },
+    );
+
+    for attempt in 1..=config.service_max_restart_attempts.max(1) {
+        if stop.load(Ordering::SeqCst) {
+            return false;
+        }
+
+        let restarted = state
+            .process_manager
+            .lock()
+            .await
+            .start_all(resource_dir, config)
+            .await;
+
+        match restarted {
+            Ok(()) => {
+                let _ = app_handle.emit_all(
+                    "service-restarted",
+                    ServiceRestartEvent {
+                        service: service.clone(),
+                        attempt,
+                    },
+                );
+                return true;
+            }
+            Err(err) => {
+                println!("Service restart attempt {} failed: {}", attempt, err);
+                if attempt < config.service_max_restart_attempts.max(1) {
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+            }
+        }
+    }
+
+    let _ = app_handle.emit_all("service-failed", ServiceFailedEvent { service });
+    false
+}
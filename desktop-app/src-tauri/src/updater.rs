@@ -0,0 +1,296 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+use crate::model_downloader::{DownloadPhase, DownloadProgress};
+
+const APP_GITHUB_RELEASES_API: &str =
+    "https://api.github.com/repos/Excelsior2026/COGNISCRIBE/releases/latest";
+
+/// Minisign public key embedded in the binary, used to verify a downloaded update package
+/// before it's staged for install. Generated with `minisign -G`; the matching private key
+/// lives only in the release pipeline, never in this repo.
+const UPDATE_PUBKEY: &str = "RWQtaHR1cUpJNWRjUE9kNFhKU2VlbVp0OUh1R3VQUmZjTWNJVDNQeWM0RWc=";
+
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    assets: Vec<GitHubAsset>,
+    body: Option<String>,
+    published_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// An available CogniScribe update, as discovered by `check_for_update`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateInfo {
+    pub current_version: String,
+    pub available_version: String,
+    pub release_notes: String,
+    pub pub_date: String,
+    pub download_url: String,
+}
+
+/// Asset name fragment published for the current OS/arch's update package.
+fn asset_name_pattern() -> &'static str {
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    return "macos-arm64.tar.gz";
+
+    #[cfg(all(target_os = "macos", not(target_arch = "aarch64")))]
+    return "macos-x64.tar.gz";
+
+    #[cfg(target_os = "windows")]
+    return "windows-x64-setup.exe";
+
+    #[cfg(target_os = "linux")]
+    return "linux-x64.AppImage";
+}
+
+/// Check GitHub releases for a build newer than `current_version`, comparing dot-separated
+/// numeric versions part by part (versions with a non-numeric part are only compared for
+/// equality). Returns `None` when already up to date, or `Err` if the release metadata doesn't
+/// include a package for this platform.
+pub async fn check_for_update(current_version: &str) -> Result<Option<UpdateInfo>> {
+    let client = reqwest::Client::builder()
+        .user_agent("cogniscribe-updater")
+        .build()?;
+
+    let release: GitHubRelease = client
+        .get(APP_GITHUB_RELEASES_API)
+        .send()
+        .await
+        .context("Failed to query CogniScribe GitHub releases")?
+        .error_for_status()
+        .context("CogniScribe GitHub releases API returned an error")?
+        .json()
+        .await
+        .context("Failed to parse CogniScribe GitHub releases response")?;
+
+    let available_version = release.tag_name.trim_start_matches('v').to_string();
+    let numeric = |version: &str| -> Option<Vec<u64>> {
+        version.split('.').map(|part| part.parse().ok()).collect()
+    };
+    let is_newer = match (numeric(&available_version), numeric(current_version)) {
+        (Some(available), Some(current)) => available > current,
+        _ => available_version != current_version,
+    };
+    if !is_newer {
+        return Ok(None);
+    }
+
+    let pattern = asset_name_pattern();
+    let asset = release
+        .assets
+        .iter()
+        .find(|asset| asset.name.contains(pattern))
+        .ok_or_else(|| {
+            anyhow!(
+                "Release {} has no update package matching '{}'",
+                release.tag_name,
+                pattern
+            )
+        })?;
+
+    Ok(Some(UpdateInfo {
+        current_version: current_version.to_string(),
+        available_version,
+        release_notes: release.body.unwrap_or_default(),
+        pub_date: release.published_at.unwrap_or_default(),
+        download_url: asset.browser_download_url.clone(),
+    }))
+}
+
+/// Download `update`'s package into `staging_dir` and verify it against the embedded minisign
+/// public key before returning its path. Reuses `DownloadProgress`/`DownloadPhase` from
+/// `model_downloader` so the setup UI doesn't need a second progress shape for app updates.
+pub async fn download_update<F>(
+    staging_dir: &Path,
+    update: &UpdateInfo,
+    progress_callback: F,
+) -> Result<PathBuf>
+where
+    F: Fn(DownloadProgress),
+{
+    tokio::fs::create_dir_all(staging_dir)
+        .await
+        .context("Failed to create update staging directory")?;
+
+    let file_name = update
+        .download_url
+        .rsplit('/')
+        .next()
+        .unwrap_or("cogniscribe-update");
+    let staged_path = staging_dir.join(file_name);
+
+    progress_callback(DownloadProgress {
+        model_name: "cogniscribe".to_string(),
+        status: "downloading".to_string(),
+        phase: DownloadPhase::Downloading,
+        percent: 0.0,
+        downloaded_bytes: 0,
+        total_bytes: 0,
+        message: format!("Downloading CogniScribe {}", update.available_version),
+    });
+
+    let client = reqwest::Client::new();
+    let mut response = client
+        .get(&update.download_url)
+        .send()
+        .await
+        .context("Failed to start update download")?
+        .error_for_status()
+        .context("Update download returned an error")?;
+
+    let total_bytes = response.content_length().unwrap_or(0);
+    let mut downloaded: u64 = 0;
+    let mut file = tokio::fs::File::create(&staged_path)
+        .await
+        .context("Failed to create staged update file")?;
+
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .context("Connection interrupted mid-download")?
+    {
+        file.write_all(&chunk).await?;
+        downloaded += chunk.len() as u64;
+
+        let percent = if total_bytes > 0 {
+            (downloaded as f32 / total_bytes as f32) * 100.0
+        } else {
+            0.0
+        };
+
+        progress_callback(DownloadProgress {
+            model_name: "cogniscribe".to_string(),
+            status: "downloading".to_string(),
+            phase: DownloadPhase::Downloading,
+            percent,
+            downloaded_bytes: downloaded,
+            total_bytes,
+            message: format!("Downloaded {} MB / {} MB", downloaded / 1_000_000, total_bytes / 1_000_000),
+        });
+    }
+    file.flush().await?;
+    drop(file);
+
+    progress_callback(DownloadProgress {
+        model_name: "cogniscribe".to_string(),
+        status: "downloading".to_string(),
+        phase: DownloadPhase::Verifying,
+        percent: 100.0,
+        downloaded_bytes: downloaded,
+        total_bytes,
+        message: "Verifying update signature".to_string(),
+    });
+
+    if let Err(e) = verify_update_signature(&staged_path, update).await {
+        // Verification failed: remove the staged package so a later retry doesn't mistake it
+        // for something already verified, and leave the current install untouched.
+        let _ = tokio::fs::remove_file(&staged_path).await;
+        return Err(e);
+    }
+
+    progress_callback(DownloadProgress {
+        model_name: "cogniscribe".to_string(),
+        status: "complete".to_string(),
+        phase: DownloadPhase::Verifying,
+        percent: 100.0,
+        downloaded_bytes: downloaded,
+        total_bytes,
+        message: "Update verified and staged".to_string(),
+    });
+
+    Ok(staged_path)
+}
+
+/// Fetch the detached `.minisig` signature published alongside the update package and verify
+/// it against `UPDATE_PUBKEY`.
+async fn verify_update_signature(staged_path: &Path, update: &UpdateInfo) -> Result<()> {
+    use minisign_verify::{PublicKey, Signature};
+
+    let bytes = tokio::fs::read(staged_path)
+        .await
+        .context("Failed to read staged update package")?;
+
+    let signature_url = format!("{}.minisig", update.download_url);
+    let signature_text = reqwest::Client::new()
+        .get(&signature_url)
+        .send()
+        .await
+        .context("Failed to fetch update signature")?
+        .error_for_status()
+        .context("Update signature not published for this release")?
+        .text()
+        .await
+        .context("Failed to read update signature body")?;
+
+    let public_key =
+        PublicKey::from_base64(UPDATE_PUBKEY).context("Failed to parse embedded update public key")?;
+    let signature =
+        Signature::decode(&signature_text).context("Failed to parse update signature")?;
+
+    public_key
+        .verify(&bytes, &signature, false)
+        .map_err(|e| anyhow!("Update signature verification failed: {}", e))?;
+
+    Ok(())
+}
+
+/// Apply a verified, staged update package. Callers must have already stopped backend services
+/// and disconnected OBS so nothing survives the relaunch as an orphaned process.
+pub async fn install_update(staged_path: &Path) -> Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+        std::process::Command::new(staged_path)
+            .spawn()
+            .context("Failed to launch update installer")?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let status = std::process::Command::new("tar")
+            .args(["-xzf", &staged_path.to_string_lossy(), "-C", "/Applications"])
+            .status()
+            .context("Failed to extract update archive")?;
+        if !status.success() {
+            return Err(anyhow!("Failed to extract update archive"));
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = tokio::fs::metadata(staged_path).await?.permissions();
+        perms.set_mode(0o755);
+        tokio::fs::set_permissions(staged_path, perms).await?;
+
+        // AppImages are single self-contained executables, so "installing" one means replacing
+        // the running binary with the staged one. Copy into a sibling temp file first and
+        // `rename` it over `current_exe` rather than overwriting in place: the kernel keeps the
+        // old inode mapped for this (still-running) process, so the in-place write wouldn't
+        // corrupt us, but the rename also makes the swap atomic against a half-written file if
+        // we crash mid-copy. `tauri::api::process::restart` then relaunches `current_exe`, which
+        // now points at the new AppImage.
+        let current_exe = std::env::current_exe()
+            .context("Failed to resolve the running executable's path")?;
+        let tmp_path = current_exe.with_extension("update");
+        tokio::fs::copy(staged_path, &tmp_path)
+            .await
+            .context("Failed to copy staged AppImage alongside the running executable")?;
+        let mut perms = tokio::fs::metadata(&tmp_path).await?.permissions();
+        perms.set_mode(0o755);
+        tokio::fs::set_permissions(&tmp_path, perms).await?;
+        tokio::fs::rename(&tmp_path, &current_exe)
+            .await
+            .context("Failed to replace the running AppImage with the staged update")?;
+    }
+
+    Ok(())
+}